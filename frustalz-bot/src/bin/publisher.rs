@@ -0,0 +1,328 @@
+mod credentials;
+mod post;
+mod retry;
+mod summary;
+
+use std::io::BufWriter;
+use std::num::NonZeroU32;
+use std::path::PathBuf;
+use std::process;
+use std::time::{self, Instant};
+
+use egg_mode::{
+    media::{media_types, UploadBuilder},
+    tweet::DraftTweet,
+};
+use frustalz_core::{
+    generate::{Generator, Quality, StopCriteria},
+    image::{Antialiazing, Coloring, GrainStrength, RetroPalette, ScreenDimensions, ToneMapping},
+    seed::{self, Cadence, DateSeed, SeedOffset},
+    status::StatusFile,
+};
+use image::RgbImage;
+use png::{Encoder, HasParameters};
+use structopt::StructOpt;
+use tokio_core::reactor;
+
+use crate::credentials::Credentials;
+use crate::post::{Post, Publisher};
+use crate::retry::RetryPolicy;
+use crate::summary::{Failure, Summary};
+
+#[derive(Debug, Clone, StructOpt)]
+pub struct Settings {
+    /// The date to use as a seed (RFC3339 or local "YYYY-MM-DD HH")
+    #[structopt(long = "date-seed")]
+    pub date_seed: Option<DateSeed>,
+
+    /// The granularity the seed date is rounded to ("30min", "hourly" or "daily")
+    #[structopt(long = "seed-cadence", default_value = "hourly")]
+    pub seed_cadence: Cadence,
+
+    /// The offset in minutes applied to the seed cadence periods, within a day
+    #[structopt(long = "seed-offset", default_value = "0")]
+    pub seed_offset: SeedOffset,
+
+    /// A salt mixed into the seed to produce different images from the same dates
+    #[structopt(long = "seed-salt")]
+    pub seed_salt: Option<String>,
+
+    /// Preset of dimensions and antialiazing ("draft", "standard" or "ultra")
+    #[structopt(long = "quality")]
+    pub quality: Option<Quality>,
+
+    /// Antialiazing used for the images generated (a power of 4)
+    #[structopt(long = "antialiazing")]
+    pub antialiazing: Option<Antialiazing>,
+
+    /// Where the images are published ("twitter" or "file")
+    #[structopt(long = "publisher", default_value = "twitter")]
+    pub publisher: Publisher,
+
+    /// The directory the file publisher writes the posts into
+    #[structopt(long = "posts-dir", parse(from_os_str), default_value = "posts")]
+    pub posts_dir: PathBuf,
+
+    /// Where the Twitter secrets are read from ("file" or "keyring")
+    #[structopt(long = "credentials", default_value = "file")]
+    pub credentials: Credentials,
+
+    /// The directory containing the secrets files when reading them from files
+    #[structopt(long = "credentials-dir", parse(from_os_str), default_value = ".")]
+    pub credentials_dir: PathBuf,
+
+    /// Generate the image without uploading it
+    #[structopt(long = "dry-run")]
+    pub dry_run: bool,
+
+    /// Generate the image and save it in a file
+    #[structopt(long = "save-image")]
+    pub save_image: bool,
+
+    /// Write a JSON summary of the run (success, timings, url) into this file
+    #[structopt(long = "summary-json", parse(from_os_str))]
+    pub summary_json: Option<PathBuf>,
+
+    /// Mirror the final shot this many times around the targeted point
+    #[structopt(long = "kaleidoscope")]
+    pub kaleidoscope: Option<NonZeroU32>,
+
+    /// Add a film grain of this strength (between 0 and 1) to the final shot
+    #[structopt(long = "film-grain")]
+    pub film_grain: Option<GrainStrength>,
+
+    /// Shift the red and blue channels of the final shot up to this many pixels
+    #[structopt(long = "chromatic-aberration")]
+    pub chromatic_aberration: Option<f64>,
+
+    /// Tone mapping applied to the colors before quantization ("reinhard" or "filmic")
+    #[structopt(long = "tone-mapping")]
+    pub tone_mapping: Option<ToneMapping>,
+
+    /// Periodically write the progress of the run into this file
+    #[structopt(long = "status-file", parse(from_os_str))]
+    pub status_file: Option<PathBuf>,
+
+    /// The maximum number of tries of each network request
+    #[structopt(long = "retry-attempts", default_value = "5")]
+    pub retry_attempts: u32,
+
+    /// The number of seconds after which a network request is not retried anymore
+    #[structopt(long = "retry-deadline", default_value = "900")]
+    pub retry_deadline: u64,
+
+    /// Paint the final shot from the orbits ("period", "binary-decomposition",
+    /// "external-angle", "potential" or "field-lines")
+    #[structopt(long = "coloring")]
+    pub coloring: Option<Coloring>,
+
+    /// Reduce the final shot to a retro palette with dithering ("ega" or "pico8")
+    #[structopt(long = "retro")]
+    pub retro: Option<RetroPalette>,
+
+    /// Stop diving when the edge density increases by less than this between two steps
+    #[structopt(long = "stop-edge-plateau")]
+    pub stop_edge_plateau: Option<f64>,
+
+    /// Stop diving when the fraction of interior pixels exceeds this (between 0 and 1)
+    #[structopt(long = "stop-interior-fraction")]
+    pub stop_interior_fraction: Option<f64>,
+
+    /// Dimensions of images used to dive into fractals
+    #[structopt(long = "dive-dimensions")]
+    pub dive_dimensions: Option<ScreenDimensions>,
+
+    /// Dimensions of the final image generated
+    #[structopt(long = "shot-dimensions")]
+    pub shot_dimensions: Option<ScreenDimensions>,
+
+    /// Whether the program produce all images while diving in the fractal
+    #[structopt(long = "no-debug-images")]
+    pub no_debug_images: bool,
+}
+
+fn image_to_png(image: RgbImage) -> Vec<u8> {
+    let (width, height) = image.dimensions();
+    let buf = image.into_raw();
+
+    let mut out = BufWriter::new(Vec::new());
+
+    {
+        let mut encoder = Encoder::new(&mut out, width, height);
+        encoder.set(png::ColorType::RGB).set(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().unwrap();
+
+        writer.write_image_data(&buf).unwrap();
+    }
+
+    out.into_inner().unwrap()
+}
+
+fn report(status_file: Option<&StatusFile>, stage: &str, percent: f64) {
+    if let Some(status_file) = status_file {
+        if let Err(e) = status_file.update(stage, percent) {
+            eprintln!("can not update the status file: {}", e);
+        }
+    }
+}
+
+fn run(
+    settings: &Settings,
+    status_file: Option<&StatusFile>,
+    summary: &mut Summary,
+) -> Result<(), Failure> {
+    let mut core = reactor::Core::new().unwrap();
+    report(status_file, "verifying", 0.0);
+
+    let handle = core.handle();
+    let retry = RetryPolicy {
+        max_attempts: settings.retry_attempts.max(1),
+        deadline: time::Duration::from_secs(settings.retry_deadline),
+        ..RetryPolicy::default()
+    };
+
+    let token = match settings.publisher {
+        Publisher::Twitter => {
+            let token = settings
+                .credentials
+                .token(&settings.credentials_dir)
+                .map_err(Failure::Credentials)?;
+
+            retry
+                .run(&mut core, || egg_mode::verify_tokens(&token, &handle))
+                .map_err(|e| Failure::Credentials(e.to_string()))?;
+
+            Some(token)
+        }
+        Publisher::File => None,
+    };
+
+    let SeedOffset(offset) = settings.seed_offset;
+    let date = match settings.date_seed.unwrap_or_default().floor(settings.seed_cadence, offset) {
+        Ok(date) => date,
+        Err(e) => {
+            eprintln!("--date-seed: {}", e);
+            process::exit(1);
+        }
+    };
+    println!("{:?}", date);
+    summary.seed_date = Some(date.0.to_rfc3339());
+    let rng = seed::rng(date, settings.seed_salt.as_deref());
+
+    let mut generator = Generator::new(rng);
+
+    if let Some(quality) = settings.quality {
+        generator.quality(quality);
+    }
+    if let Some(dims) = settings.shot_dimensions {
+        generator.shot_dimensions(dims);
+    }
+    if let Some(dims) = settings.dive_dimensions {
+        generator.dive_dimensions(dims);
+    }
+    if let Some(anti) = settings.antialiazing {
+        generator.antialiazing(anti);
+    }
+    generator.debug_images(!settings.no_debug_images);
+    generator.kaleidoscope(settings.kaleidoscope);
+    generator.film_grain(settings.film_grain);
+    generator.chromatic_aberration(settings.chromatic_aberration);
+    generator.tone_mapping(settings.tone_mapping);
+    generator.retro(settings.retro);
+    generator.coloring(settings.coloring);
+    generator.stop_criteria(StopCriteria {
+        edge_density_plateau: settings.stop_edge_plateau,
+        interior_fraction: settings.stop_interior_fraction,
+    });
+    generator.status_file(status_file.cloned());
+
+    let start = Instant::now();
+    let (info, image) = generator.generate();
+    summary.timings.generation = Some(start.elapsed().as_secs_f64());
+    summary.info = Some(info.to_string());
+
+    if settings.save_image || settings.dry_run {
+        match image.save("./image.png") {
+            Ok(_) => println!("image saved to \"./image.png\""),
+            Err(e) => eprintln!("can not save image to \"./image.png\": {}", e),
+        }
+    }
+
+    if settings.dry_run {
+        return Ok(());
+    }
+
+    let image = image_to_png(image);
+    let message = info.to_string();
+
+    let token = match token {
+        Some(token) => token,
+        None => {
+            report(status_file, "publishing", 0.0);
+            let start = Instant::now();
+            let name = Post::name(&date);
+            let seed_date = date.0.to_rfc3339();
+            let post =
+                Post { text: &message, seed_date: &seed_date, image: format!("{}.png", name) };
+            let path = post
+                .write_to(&settings.posts_dir, &name, &image)
+                .map_err(|e| Failure::Publication(format!("can not write the post: {}", e)))?;
+            summary.timings.publication = Some(start.elapsed().as_secs_f64());
+            println!("post written to {:?}", path);
+
+            return Ok(());
+        }
+    };
+
+    report(status_file, "uploading", 0.0);
+    let start = Instant::now();
+    let media_handle = retry
+        .run(&mut core, || {
+            let builder = UploadBuilder::new(&image[..], media_types::image_png());
+            builder.call(&token, &handle)
+        })
+        .map_err(|e| Failure::Publication(format!("can not upload the image: {}", e)))?;
+    summary.timings.upload = Some(start.elapsed().as_secs_f64());
+
+    report(status_file, "publishing", 0.0);
+    let start = Instant::now();
+    let draft = DraftTweet::new(message).media_ids(&[media_handle.id]);
+    // a tweet that timed out may have been posted, only rate limits are retried
+    let tweet = retry
+        .run_rate_limited(&mut core, || draft.send(&token, &handle))
+        .map_err(|e| Failure::Publication(format!("can not send the tweet: {}", e)))?;
+    summary.timings.publication = Some(start.elapsed().as_secs_f64());
+
+    if let Some(url) = tweet.entities.urls.first().map(|u| &u.url) {
+        println!("tweet url: {}", url);
+        summary.tweet_url = Some(url.clone());
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let settings = Settings::from_args();
+
+    let status_file = settings.status_file.clone().map(StatusFile::new);
+
+    let mut summary = Summary::default();
+    let result = run(&settings, status_file.as_ref(), &mut summary);
+    report(status_file.as_ref(), if result.is_ok() { "done" } else { "failed" }, 100.0);
+
+    summary.success = result.is_ok();
+    if let Err(failure) = &result {
+        eprintln!("{}", failure);
+        summary.exit_code = failure.exit_code();
+        summary.error = Some(failure.to_string());
+    }
+
+    if let Some(path) = &settings.summary_json {
+        if let Err(e) = summary.write_to(path) {
+            eprintln!("can not write the summary to {:?}: {}", path, e);
+        }
+    }
+
+    process::exit(summary.exit_code);
+}
@@ -0,0 +1,57 @@
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use egg_mode::{KeyPair, Token};
+use keyring::Keyring;
+
+/// The keyring service under which the secrets are stored.
+const KEYRING_SERVICE: &str = "frustalz";
+
+/// Where the Twitter secrets are read from, each secret is named
+/// `consumer_key`, `consumer_secret`, `access_key` or `access_secret`.
+#[derive(Debug, Copy, Clone)]
+pub enum Credentials {
+    /// Plaintext files in the credentials directory.
+    File,
+    /// Entries of the OS keyring (secret-service, keychain, credential manager).
+    Keyring,
+}
+
+impl Credentials {
+    fn read(&self, dir: &Path, name: &str) -> Result<String, String> {
+        let secret = match self {
+            Credentials::File => fs::read_to_string(dir.join(name))
+                .map_err(|e| format!("can not read the {} file: {}", name, e))?,
+            Credentials::Keyring => Keyring::new(KEYRING_SERVICE, name)
+                .get_password()
+                .map_err(|e| format!("can not read {} from the keyring: {}", name, e))?,
+        };
+
+        Ok(secret.trim().to_string())
+    }
+
+    pub fn token(&self, dir: &Path) -> Result<Token<'static>, String> {
+        let consumer_key = self.read(dir, "consumer_key")?;
+        let consumer_secret = self.read(dir, "consumer_secret")?;
+        let access_key = self.read(dir, "access_key")?;
+        let access_secret = self.read(dir, "access_secret")?;
+
+        Ok(Token::Access {
+            consumer: KeyPair::new(consumer_key, consumer_secret),
+            access: KeyPair::new(access_key, access_secret),
+        })
+    }
+}
+
+impl FromStr for Credentials {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "file" => Ok(Credentials::File),
+            "keyring" => Ok(Credentials::Keyring),
+            _ => Err("invalid credentials source, expected \"file\" or \"keyring\""),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use frustalz_core::seed::DateSeed;
+use serde_derive::Serialize;
+
+/// Where the generated images are published.
+#[derive(Debug, Copy, Clone)]
+pub enum Publisher {
+    /// Tweeted using the credentials.
+    Twitter,
+    /// Written into the posts directory, without any network access.
+    File,
+}
+
+impl FromStr for Publisher {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "twitter" => Ok(Publisher::Twitter),
+            "file" => Ok(Publisher::File),
+            _ => Err("invalid publisher, expected \"twitter\" or \"file\""),
+        }
+    }
+}
+
+/// The would-be post, written next to its image by the file publisher.
+#[derive(Debug, Serialize)]
+pub struct Post<'a> {
+    pub text: &'a str,
+    pub seed_date: &'a str,
+    pub image: String,
+}
+
+impl<'a> Post<'a> {
+    /// The name of the files of the post seeded by `date`, e.g. `2019-01-01T00-00`.
+    pub fn name(date: &DateSeed) -> String {
+        date.0.format("%Y-%m-%dT%H-%M").to_string()
+    }
+
+    /// Writes the image and the post into `dir` as `<name>.png` and `<name>.json`,
+    /// returns the path of the post.
+    pub fn write_to(&self, dir: &Path, name: &str, png: &[u8]) -> io::Result<PathBuf> {
+        fs::create_dir_all(dir)?;
+        fs::write(dir.join(&self.image), png)?;
+
+        let path = dir.join(format!("{}.json", name));
+        let file = fs::File::create(&path)?;
+        serde_json::to_writer_pretty(file, self).map_err(io::Error::from)?;
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use frustalz_core::generate::{Generator, MemorySink};
+    use frustalz_core::image::{Antialiazing, ScreenDimensions};
+    use frustalz_core::seed;
+    use std::env;
+    use std::process;
+
+    #[test]
+    fn parse_publisher() {
+        match "twitter".parse::<Publisher>() {
+            Ok(Publisher::Twitter) => (),
+            other => panic!("unexpected publisher {:?}", other),
+        }
+        match " file ".parse::<Publisher>() {
+            Ok(Publisher::File) => (),
+            other => panic!("unexpected publisher {:?}", other),
+        }
+        assert!("mastodon".parse::<Publisher>().is_err());
+    }
+
+    #[test]
+    fn write_post() {
+        let root = env::temp_dir().join(format!("frustalz-post-{}", process::id()));
+        let dir = root.join("posts");
+
+        let png = [0x89, b'P', b'N', b'G'];
+        let post = Post {
+            text: "Here is the mandelbrot fractal",
+            seed_date: "2019-01-01T00:00:00+00:00",
+            image: "2019-01-01T00-00.png".to_string(),
+        };
+
+        let path = post.write_to(&dir, "2019-01-01T00-00", &png).unwrap();
+        assert_eq!(path, dir.join("2019-01-01T00-00.json"));
+        assert_eq!(fs::read(dir.join("2019-01-01T00-00.png")).unwrap(), png);
+
+        let json: serde_json::Value = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+        assert_eq!(json["text"], "Here is the mandelbrot fractal");
+        assert_eq!(json["seed_date"], "2019-01-01T00:00:00+00:00");
+        assert_eq!(json["image"], "2019-01-01T00-00.png");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn publish_generated() {
+        let root = env::temp_dir().join(format!("frustalz-publish-{}", process::id()));
+        let dir = root.join("posts");
+
+        // 2019-01-01T00:00:00Z
+        let date = DateSeed(Utc.timestamp_opt(1_546_300_800, 0).unwrap());
+
+        let mut generator = Generator::new(seed::rng(date, None));
+        generator
+            .dive_dimensions(ScreenDimensions(24, 16))
+            .shot_dimensions(ScreenDimensions(32, 24))
+            .antialiazing(Antialiazing::new(1).unwrap())
+            .debug_sink(MemorySink::new());
+        let (info, image) = generator.generate();
+
+        let message = info.to_string();
+        let name = Post::name(&date);
+        let seed_date = date.0.to_rfc3339();
+        let post = Post { text: &message, seed_date: &seed_date, image: format!("{}.png", name) };
+
+        let path = post.write_to(&dir, &name, &crate::image_to_png(image)).unwrap();
+        assert_eq!(path, dir.join("2019-01-01T00-00.json"));
+
+        let json: serde_json::Value = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+        assert_eq!(json["text"], message.as_str());
+        assert_eq!(json["seed_date"], "2019-01-01T00:00:00+00:00");
+        assert_eq!(json["image"], "2019-01-01T00-00.png");
+
+        let png = image::open(dir.join("2019-01-01T00-00.png")).unwrap();
+        assert_eq!(png.to_rgb().dimensions(), (32, 24));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,153 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use egg_mode::error::Error;
+use futures::Future;
+use rand::Rng;
+use tokio_core::reactor;
+
+/// How many times and for how long a network request is retried.
+#[derive(Debug, Copy, Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of tries, the first one included.
+    pub max_attempts: u32,
+    /// The delay before the first retry, doubled for each following one.
+    pub base_delay: Duration,
+    /// The maximum delay between two tries.
+    pub max_delay: Duration,
+    /// The total time after which we stop retrying.
+    pub deadline: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            deadline: Duration::from_secs(15 * 60),
+        }
+    }
+}
+
+/// Whether an error is worth retrying the request for.
+fn is_transient(error: &Error) -> bool {
+    match error {
+        Error::RateLimit(_) | Error::NetError(_) | Error::IOError(_) => true,
+        Error::BadStatus(status) => status.is_server_error(),
+        _ => false,
+    }
+}
+
+impl RetryPolicy {
+    /// The exponential delay to wait before the given retry, with a full jitter.
+    fn backoff(&self, retry: u32) -> Duration {
+        let delay = self.base_delay.checked_mul(1 << retry.min(16)).unwrap_or(self.max_delay);
+        let millis = delay.min(self.max_delay).as_millis() as u64;
+        Duration::from_millis(rand::thread_rng().gen_range(0, millis + 1))
+    }
+
+    /// Runs the request produced by `request` until it succeeds, fails with a non
+    /// transient error or the policy is exhausted, rate limits are waited until
+    /// the reset time reported by the platform.
+    ///
+    /// The request must be idempotent: a network error can hide a request
+    /// that was processed by the platform.
+    pub fn run<F, R>(&self, core: &mut reactor::Core, request: F) -> Result<R::Item, Error>
+    where
+        F: FnMut() -> R,
+        R: Future<Error = Error>,
+    {
+        self.run_while(core, request, is_transient)
+    }
+
+    /// Runs the request produced by `request`, only retrying it when it was rate limited,
+    /// this is the only error for which we know that the request was not processed.
+    ///
+    /// Used for the requests that must not be repeated, like posting a tweet.
+    pub fn run_rate_limited<F, R>(
+        &self,
+        core: &mut reactor::Core,
+        request: F,
+    ) -> Result<R::Item, Error>
+    where
+        F: FnMut() -> R,
+        R: Future<Error = Error>,
+    {
+        self.run_while(core, request, |error| match error {
+            Error::RateLimit(_) => true,
+            _ => false,
+        })
+    }
+
+    fn run_while<F, R, P>(
+        &self,
+        core: &mut reactor::Core,
+        mut request: F,
+        retry: P,
+    ) -> Result<R::Item, Error>
+    where
+        F: FnMut() -> R,
+        R: Future<Error = Error>,
+        P: Fn(&Error) -> bool,
+    {
+        let start = Instant::now();
+        let mut attempt = 1;
+
+        loop {
+            let error = match core.run(request()) {
+                Err(error) if retry(&error) => error,
+                result => return result,
+            };
+
+            let wait = match error {
+                Error::RateLimit(reset) => {
+                    let secs = (i64::from(reset) - Utc::now().timestamp()).max(1) as u64;
+                    Duration::from_secs(secs)
+                }
+                _ => self.backoff(attempt - 1),
+            };
+
+            if attempt >= self.max_attempts || start.elapsed() + wait > self.deadline {
+                return Err(error);
+            }
+
+            eprintln!("{}, retrying in {:.1} seconds", error, wait.as_secs_f64());
+            thread::sleep(wait);
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_is_bounded() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(2),
+            ..RetryPolicy::default()
+        };
+
+        for retry in 0..8 {
+            let bound = (policy.base_delay * (1 << retry)).min(policy.max_delay);
+            for _ in 0..100 {
+                assert!(policy.backoff(retry) <= bound);
+            }
+        }
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let policy = RetryPolicy::default();
+
+        for &retry in &[16, 17, 31, 64, u32::max_value()] {
+            for _ in 0..100 {
+                assert!(policy.backoff(retry) <= policy.max_delay);
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use serde_derive::Serialize;
+
+/// Why a run failed, each reason exits with its own code.
+#[derive(Debug)]
+pub enum Failure {
+    /// The credentials can not be read or are refused by the platform.
+    Credentials(String),
+    /// The image could not be uploaded or the post sent.
+    Publication(String),
+}
+
+impl Failure {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Failure::Credentials(_) => 2,
+            Failure::Publication(_) => 3,
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Credentials(e) => write!(f, "credentials error: {}", e),
+            Failure::Publication(e) => write!(f, "publication error: {}", e),
+        }
+    }
+}
+
+/// The durations of each stage, in seconds.
+#[derive(Debug, Default, Serialize)]
+pub struct Timings {
+    pub generation: Option<f64>,
+    pub upload: Option<f64>,
+    pub publication: Option<f64>,
+}
+
+/// A machine readable report of a run, for schedulers.
+#[derive(Debug, Default, Serialize)]
+pub struct Summary {
+    pub success: bool,
+    pub exit_code: i32,
+    pub seed_date: Option<String>,
+    pub info: Option<String>,
+    pub tweet_url: Option<String>,
+    pub error: Option<String>,
+    pub timings: Timings,
+}
+
+impl Summary {
+    pub fn write_to(&self, path: &Path) -> io::Result<()> {
+        let file = File::create(path)?;
+        serde_json::to_writer_pretty(file, self).map_err(io::Error::from)
+    }
+}
@@ -0,0 +1,61 @@
+use std::path::{Path, PathBuf};
+use std::process;
+
+use frustalz_core::image::{difference_heatmap, psnr, ssim};
+use image::RgbImage;
+use structopt::StructOpt;
+
+#[derive(Debug, Clone, StructOpt)]
+pub struct Settings {
+    /// The reference image
+    #[structopt(parse(from_os_str))]
+    pub reference: PathBuf,
+
+    /// The image to compare with the reference
+    #[structopt(parse(from_os_str))]
+    pub image: PathBuf,
+
+    /// Where the difference heatmap is written
+    #[structopt(long = "output", parse(from_os_str), default_value = "./diff.png")]
+    pub output: PathBuf,
+}
+
+fn open(path: &Path) -> RgbImage {
+    match image::open(path) {
+        Ok(image) => image.to_rgb(),
+        Err(e) => {
+            eprintln!("can not open image {:?}: {}", path, e);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let settings = Settings::from_args();
+
+    let reference = open(&settings.reference);
+    let image = open(&settings.image);
+
+    if reference.dimensions() != image.dimensions() {
+        eprintln!(
+            "images dimensions differ: {:?} and {:?}",
+            reference.dimensions(),
+            image.dimensions()
+        );
+        process::exit(1);
+    }
+
+    if reference.width() == 0 || reference.height() == 0 {
+        eprintln!("images are empty");
+        process::exit(1);
+    }
+
+    println!("PSNR: {:.3} dB", psnr(&reference, &image));
+    println!("SSIM: {:.5}", ssim(&reference, &image));
+
+    let heatmap = difference_heatmap(&reference, &image);
+    match heatmap.save(&settings.output) {
+        Ok(_) => println!("heatmap saved to {:?}", settings.output),
+        Err(e) => eprintln!("can not save heatmap to {:?}: {}", settings.output, e),
+    }
+}
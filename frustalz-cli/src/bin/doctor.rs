@@ -0,0 +1,65 @@
+use std::time::Instant;
+
+use frustalz_core::{capabilities::Capability, image::produce_image, Camera, Mandelbrot};
+use image::Rgb;
+
+fn print_cpu_features() {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        let features = [
+            ("sse2", is_x86_feature_detected!("sse2")),
+            ("sse4.1", is_x86_feature_detected!("sse4.1")),
+            ("avx", is_x86_feature_detected!("avx")),
+            ("avx2", is_x86_feature_detected!("avx2")),
+            ("fma", is_x86_feature_detected!("fma")),
+        ];
+        for (name, detected) in &features {
+            println!("  {:<8} {}", name, if *detected { "yes" } else { "no" });
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        let detected = std::arch::is_aarch64_feature_detected!("neon");
+        println!("  {:<8} {}", "neon", if detected { "yes" } else { "no" });
+    }
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+    println!("  no detection available for this architecture");
+}
+
+/// Renders the whole mandelbrot set a few times and returns the best throughput,
+/// in millions of pixels per second.
+fn benchmark() -> f64 {
+    let dimensions = (512, 512);
+    let camera = Camera::new([512.0, 512.0]);
+    let pixels = 512.0 * 512.0;
+
+    (0..5)
+        .map(|_| {
+            let start = Instant::now();
+            produce_image(&Mandelbrot::new(), &camera, dimensions, None, |i| Rgb { data: [i; 3] });
+            pixels / start.elapsed().as_secs_f64() / 1_000_000.0
+        })
+        .fold(0.0, f64::max)
+}
+
+fn main() {
+    println!("version: {}", env!("CARGO_PKG_VERSION"));
+    println!("target: {} {}", std::env::consts::ARCH, std::env::consts::OS);
+
+    println!("cpu features:");
+    print_cpu_features();
+
+    println!("cargo features:");
+    for capability in Capability::all() {
+        let status = if capability.is_enabled() { "enabled" } else { "disabled" };
+        println!("  {:<8} {}", capability.feature(), status);
+    }
+
+    println!("gpu: no gpu backend available");
+    println!("encoders: png");
+    println!("threads: {}", rayon::current_num_threads());
+
+    println!("benchmark: {:.2} Mpixels/s (512x512 mandelbrot)", benchmark());
+}
@@ -0,0 +1,246 @@
+use std::num::NonZeroU32;
+use std::path::PathBuf;
+use std::process;
+
+use frustalz_core::{
+    capabilities::Capability,
+    generate::{Generator, Quality, StopCriteria},
+    image::{
+        braille_art, scopes, text_art, wrap_discontinuity, Antialiazing, Coloring, GrainStrength,
+        RetroPalette, ScreenDimensions, TextColor, ToneMapping, DEFAULT_RAMP,
+    },
+    seed::{self, Cadence, DateSeed, SeedOffset},
+    Camera,
+};
+use structopt::StructOpt;
+
+#[derive(Debug, Clone, StructOpt)]
+pub struct Settings {
+    /// The date to use as a seed (RFC3339 or local "YYYY-MM-DD HH")
+    #[structopt(long = "date-seed")]
+    pub date_seed: Option<DateSeed>,
+
+    /// The granularity the seed date is rounded to ("30min", "hourly" or "daily")
+    #[structopt(long = "seed-cadence", default_value = "hourly")]
+    pub seed_cadence: Cadence,
+
+    /// The offset in minutes applied to the seed cadence periods, within a day
+    #[structopt(long = "seed-offset", default_value = "0")]
+    pub seed_offset: SeedOffset,
+
+    /// A salt mixed into the seed to produce different images from the same dates
+    #[structopt(long = "seed-salt")]
+    pub seed_salt: Option<String>,
+
+    /// Preset of dimensions and antialiazing ("draft", "standard" or "ultra")
+    #[structopt(long = "quality")]
+    pub quality: Option<Quality>,
+
+    /// Antialiazing used for the images generated (a power of 4)
+    #[structopt(long = "antialiazing")]
+    pub antialiazing: Option<Antialiazing>,
+
+    /// Mirror the final shot this many times around the targeted point
+    #[structopt(long = "kaleidoscope")]
+    pub kaleidoscope: Option<NonZeroU32>,
+
+    /// Add a film grain of this strength (between 0 and 1) to the final shot
+    #[structopt(long = "film-grain")]
+    pub film_grain: Option<GrainStrength>,
+
+    /// Shift the red and blue channels of the final shot up to this many pixels
+    #[structopt(long = "chromatic-aberration")]
+    pub chromatic_aberration: Option<f64>,
+
+    /// Tone mapping applied to the colors before quantization ("reinhard" or "filmic")
+    #[structopt(long = "tone-mapping")]
+    pub tone_mapping: Option<ToneMapping>,
+
+    /// Paint the final shot from the orbits ("period", "binary-decomposition",
+    /// "external-angle", "potential" or "field-lines"), not with a zoom strip
+    #[structopt(long = "coloring")]
+    pub coloring: Option<Coloring>,
+
+    /// Reduce the final shot to a retro palette with dithering ("ega" or "pico8")
+    #[structopt(long = "retro")]
+    pub retro: Option<RetroPalette>,
+
+    /// Stop diving when the edge density increases by less than this between two steps
+    #[structopt(long = "stop-edge-plateau")]
+    pub stop_edge_plateau: Option<f64>,
+
+    /// Stop diving when the fraction of interior pixels exceeds this (between 0 and 1)
+    #[structopt(long = "stop-interior-fraction")]
+    pub stop_interior_fraction: Option<f64>,
+
+    /// Dimensions of images used to dive into fractals
+    #[structopt(long = "dive-dimensions")]
+    pub dive_dimensions: Option<ScreenDimensions>,
+
+    /// Dimensions of the final image generated
+    #[structopt(long = "shot-dimensions")]
+    pub shot_dimensions: Option<ScreenDimensions>,
+
+    /// Whether the program produce all images while diving in the fractal
+    #[structopt(long = "no-debug-images")]
+    pub no_debug_images: bool,
+
+    /// Produce a strip of this many shots zooming from the whole fractal to the final one
+    #[structopt(long = "zoom-strip")]
+    pub zoom_strip: Option<NonZeroU32>,
+
+    /// Produce a tileable image by blending this many pixels of its edges
+    #[structopt(long = "seamless")]
+    pub seamless: Option<u32>,
+
+    /// Write the julia fractal of each mandelbrot dive step next to it, with the debug images
+    #[structopt(long = "julia-companion")]
+    pub julia_companion: bool,
+
+    /// Also save the final image with its histogram and waveform to "./scopes.png"
+    #[structopt(long = "scopes")]
+    pub scopes: bool,
+
+    /// Also print the final image as text art of this many columns
+    #[structopt(long = "text-art")]
+    pub text_art: Option<u32>,
+
+    /// The characters used for the text art, from the darkest to the brightest
+    #[structopt(long = "text-ramp", raw(default_value = "DEFAULT_RAMP"))]
+    pub text_ramp: String,
+
+    /// Draw the text art with braille dots (2 by 4 pixels per character) instead of the ramp
+    #[structopt(long = "text-braille")]
+    pub text_braille: bool,
+
+    /// How the text art is colored ("none", "ansi" or "truecolor")
+    #[structopt(long = "text-color", default_value = "none")]
+    pub text_color: TextColor,
+
+    /// Write every decision taken while diving into this file
+    #[structopt(long = "dive-trace", parse(from_os_str))]
+    pub dive_trace: Option<PathBuf>,
+
+    /// Print the deepest zoom the final shot can be rendered at around the center and exit
+    #[structopt(long = "print-max-zoom")]
+    pub print_max_zoom: bool,
+
+    /// Show the dive and the final render in a window (requires the gui feature)
+    #[structopt(long = "preview")]
+    pub preview: bool,
+}
+
+fn main() {
+    let settings = Settings::from_args();
+
+    if settings.print_max_zoom {
+        // the final shot is the render with the most pixels, once supersampled
+        let quality = settings.quality.unwrap_or_default();
+        let (width, height) =
+            settings.shot_dimensions.unwrap_or_else(|| quality.shot_dimensions()).as_tuple();
+        let antialiazing: u32 =
+            settings.antialiazing.unwrap_or_else(|| quality.antialiazing()).into();
+        let camera = Camera::new([(width * antialiazing) as f64, (height * antialiazing) as f64]);
+        let max_zoom = camera.max_zoom::<f64>();
+        println!("max zoom: {:e} (magnified {:.3e}x)", max_zoom, max_zoom.recip());
+        return;
+    }
+
+    let rng = {
+        let SeedOffset(offset) = settings.seed_offset;
+        let date = match settings.date_seed.unwrap_or_default().floor(settings.seed_cadence, offset)
+        {
+            Ok(date) => date,
+            Err(e) => {
+                eprintln!("--date-seed: {}", e);
+                process::exit(1);
+            }
+        };
+        println!("{:?}", date);
+
+        seed::rng(date, settings.seed_salt.as_deref())
+    };
+
+    let mut generator = Generator::new(rng);
+
+    if let Some(quality) = settings.quality {
+        generator.quality(quality);
+    }
+    if let Some(dims) = settings.shot_dimensions {
+        generator.shot_dimensions(dims);
+    }
+    if let Some(dims) = settings.dive_dimensions {
+        generator.dive_dimensions(dims);
+    }
+    if let Some(anti) = settings.antialiazing {
+        generator.antialiazing(anti);
+    }
+    generator.debug_images(!settings.no_debug_images);
+    generator.kaleidoscope(settings.kaleidoscope);
+    generator.film_grain(settings.film_grain);
+    generator.chromatic_aberration(settings.chromatic_aberration);
+    generator.tone_mapping(settings.tone_mapping);
+    generator.retro(settings.retro);
+    generator.coloring(settings.coloring);
+    generator.stop_criteria(StopCriteria {
+        edge_density_plateau: settings.stop_edge_plateau,
+        interior_fraction: settings.stop_interior_fraction,
+    });
+    generator.dive_trace(settings.dive_trace);
+    generator.zoom_strip(settings.zoom_strip);
+    generator.seamless(settings.seamless);
+    generator.julia_companion(settings.julia_companion);
+
+    if settings.julia_companion && settings.no_debug_images {
+        eprintln!("--julia-companion: can not be used with --no-debug-images");
+        process::exit(1);
+    }
+
+    if settings.coloring.is_some() && settings.zoom_strip.is_some() {
+        eprintln!("--coloring: can not be used with --zoom-strip");
+        process::exit(1);
+    }
+
+    if settings.preview {
+        if let Err(e) = Capability::Gui.require() {
+            eprintln!("--preview: {}", e);
+            process::exit(1);
+        }
+    }
+
+    #[cfg(feature = "gui")]
+    generator.preview(settings.preview);
+
+    let (info, image) = generator.generate();
+
+    println!("{}", info);
+
+    if let Some(reason) = &info.stop_reason {
+        println!("{}", reason);
+    }
+
+    if settings.seamless.is_some() {
+        println!("wrap discontinuity: {:.3} (1.0 is seamless)", wrap_discontinuity(&image));
+    }
+
+    if let Some(columns) = settings.text_art {
+        let text = if settings.text_braille {
+            braille_art(&image, columns, settings.text_color)
+        } else {
+            text_art(&image, columns, &settings.text_ramp, settings.text_color)
+        };
+        print!("{}", text);
+    }
+
+    if settings.scopes {
+        match scopes(&image).save("./scopes.png") {
+            Ok(_) => println!("scopes saved to \"./scopes.png\""),
+            Err(e) => eprintln!("can not save scopes to \"./scopes.png\": {}", e),
+        }
+    }
+
+    match image.save("./image.png") {
+        Ok(_) => println!("image saved to \"./image.png\""),
+        Err(e) => eprintln!("can not save image to \"./image.png\": {}", e),
+    }
+}
@@ -0,0 +1,125 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use frustalz_core::{
+    fractal::{Julia, Mandelbrot, Orbit},
+    generate::FractalType,
+    image::{produce_image, ScreenDimensions},
+    Camera,
+};
+use image::{Rgb, RgbImage};
+use structopt::StructOpt;
+
+/// A pair of numbers formatted as `x,y`.
+#[derive(Debug, Copy, Clone)]
+pub struct Pair(f64, f64);
+
+impl FromStr for Pair {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut splitted = s.trim().split(',');
+
+        let invalid_msg = "invalid pair format, expected \"x,y\"";
+
+        let x = splitted.next().ok_or(invalid_msg)?;
+        let y = splitted.next().ok_or(invalid_msg)?;
+        if splitted.next().is_some() {
+            return Err(invalid_msg);
+        }
+
+        let x = x.trim().parse().map_err(|_| "invalid first number")?;
+        let y = y.trim().parse().map_err(|_| "invalid second number")?;
+
+        Ok(Pair(x, y))
+    }
+}
+
+#[derive(Debug, Clone, StructOpt)]
+// coordinates often start with a minus sign, they must not be taken for flags
+#[structopt(raw(setting = "structopt::clap::AppSettings::AllowLeadingHyphen"))]
+pub struct Settings {
+    /// The point whose orbit is plotted, in world coordinates
+    pub point: Pair,
+
+    /// The fractal to render ("julia" or "mandelbrot")
+    #[structopt(long = "fractal", default_value = "mandelbrot")]
+    pub fractal: FractalType,
+
+    /// The domain of the julia fractal
+    #[structopt(long = "domain", default_value = "-0.8,0.156")]
+    pub domain: Pair,
+
+    /// The center of the camera, in world coordinates
+    #[structopt(long = "center", default_value = "0,0")]
+    pub center: Pair,
+
+    /// The zoom of the camera
+    #[structopt(long = "zoom", default_value = "1")]
+    pub zoom: f64,
+
+    /// Dimensions of the image generated
+    #[structopt(long = "dimensions", default_value = "800x600")]
+    pub dimensions: ScreenDimensions,
+
+    /// Where the image is written
+    #[structopt(long = "output", parse(from_os_str), default_value = "./orbit.png")]
+    pub output: PathBuf,
+}
+
+fn put_pixel_checked(image: &mut RgbImage, x: f64, y: f64, color: Rgb<u8>) {
+    let (width, height) = image.dimensions();
+    if x >= 0.0 && y >= 0.0 && x < width as f64 && y < height as f64 {
+        image.put_pixel(x as u32, y as u32, color);
+    }
+}
+
+/// Draws a line between two points in screen coordinates,
+/// the parts outside of the image are ignored.
+fn draw_line(image: &mut RgbImage, from: [f64; 2], to: [f64; 2], color: Rgb<u8>) {
+    let [x0, y0] = from;
+    let [x1, y1] = to;
+
+    // avoid drawing millions of pixels for points that escaped far away
+    let steps = (x1 - x0).abs().max((y1 - y0).abs()).ceil().min(10_000.0) as usize;
+    for i in 0..=steps {
+        let t = if steps == 0 { 0.0 } else { i as f64 / steps as f64 };
+        put_pixel_checked(image, x0 + t * (x1 - x0), y0 + t * (y1 - y0), color);
+    }
+}
+
+fn main() {
+    let settings = Settings::from_args();
+
+    let fractal: Box<dyn Orbit + Sync> = match settings.fractal {
+        FractalType::Julia => Box::new(Julia::new(settings.domain.0, settings.domain.1)),
+        FractalType::Mandelbrot => Box::new(Mandelbrot::new()),
+    };
+
+    let dimensions = settings.dimensions.as_tuple();
+    let (width, height) = dimensions;
+    let camera = Camera {
+        screen_size: [width as f64, height as f64],
+        center: [settings.center.0, settings.center.1],
+        zoom: settings.zoom,
+    };
+
+    let mut image = produce_image(&fractal, &camera, dimensions, None, |i| Rgb { data: [i; 3] });
+
+    let Pair(x, y) = settings.point;
+    let orbit = fractal.orbit(x, y);
+    println!("{} iterations", orbit.len() - 1);
+
+    let points: Vec<_> = orbit.iter().map(|z| camera.world_to_screen([z.re, z.im])).collect();
+    for segment in points.windows(2) {
+        draw_line(&mut image, segment[0], segment[1], Rgb { data: [255, 0, 0] });
+    }
+    for &[x, y] in &points {
+        put_pixel_checked(&mut image, x, y, Rgb { data: [255, 255, 0] });
+    }
+
+    match image.save(&settings.output) {
+        Ok(_) => println!("image saved to {:?}", settings.output),
+        Err(e) => eprintln!("can not save image to {:?}: {}", settings.output, e),
+    }
+}
@@ -0,0 +1,87 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use frustalz_core::{
+    fractal::{Fractal, Julia, Mandelbrot},
+    generate::{DiveTrace, FractalType},
+    image::{edges, produce_image},
+};
+use image::{Rgb, RgbImage};
+use structopt::StructOpt;
+
+#[derive(Debug, Clone, StructOpt)]
+pub struct Settings {
+    /// The dive trace file to replay
+    #[structopt(parse(from_os_str))]
+    pub trace: PathBuf,
+
+    /// The directory where the replayed steps images are written
+    #[structopt(long = "output-dir", parse(from_os_str), default_value = ".")]
+    pub output_dir: PathBuf,
+}
+
+/// Draws a small square centered on the given point.
+fn mark(image: &mut RgbImage, point: (u32, u32), color: Rgb<u8>) {
+    let (width, height) = image.dimensions();
+    let (x, y) = point;
+
+    for px in x.saturating_sub(2)..(x + 3).min(width) {
+        for py in y.saturating_sub(2)..(y + 3).min(height) {
+            image.put_pixel(px, py, color);
+        }
+    }
+}
+
+fn open(path: &Path) -> DiveTrace {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("can not open the dive trace {:?}: {}", path, e);
+            process::exit(1);
+        }
+    };
+
+    match serde_json::from_reader(file) {
+        Ok(trace) => trace,
+        Err(e) => {
+            eprintln!("invalid dive trace {:?}: {}", path, e);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let settings = Settings::from_args();
+
+    let trace = open(&settings.trace);
+
+    let fractal: Box<dyn Fractal + Sync> = match trace.fractal_type {
+        FractalType::Julia => Box::new(Julia::new(trace.domain[0], trace.domain[1])),
+        FractalType::Mandelbrot => Box::new(Mandelbrot::new()),
+    };
+
+    for (i, step) in trace.steps.iter().enumerate() {
+        let camera = step.camera(trace.dimensions);
+        let grayscaled =
+            produce_image(&fractal, &camera, trace.dimensions, None, |i| Rgb { data: [i; 3] });
+        let mut image = edges(&grayscaled);
+
+        if let Some(start) = step.start {
+            mark(&mut image, start, Rgb { data: [0, 0, 255] });
+        }
+        if let Some(candidate) = step.black_point {
+            mark(&mut image, candidate.point, Rgb { data: [255, 0, 0] });
+        }
+        if let Some(target) = step.target {
+            mark(&mut image, target, Rgb { data: [0, 255, 0] });
+        }
+
+        println!("step {:03}: {:?}", i, step);
+
+        let path = settings.output_dir.join(format!("replay-dive-{:03}.png", i));
+        if let Err(e) = image.save(&path) {
+            eprintln!("can not save image to {:?}: {}", path, e);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use frustalz_core::generate::find_point;
+use image::{Rgb, RgbImage};
+use pathfinding::dijkstra::dijkstra;
+
+/// The previous implementation, allocating a `Vec` for each visited pixel.
+fn find_point_vec(start: (u32, u32), image: &RgbImage) -> Option<(u32, u32)> {
+    let (width, height) = image.dimensions();
+
+    let result = dijkstra(
+        &start,
+        |&(x, y)| {
+            let mut neighbours = Vec::new();
+            if x > 0 {
+                neighbours.push(((x - 1, y), 1))
+            }
+            if y > 0 {
+                neighbours.push(((x, y - 1), 1))
+            }
+            if x < width - 1 {
+                neighbours.push(((x + 1, y), 1))
+            }
+            if y < height - 1 {
+                neighbours.push(((x, y + 1), 1))
+            }
+            neighbours
+        },
+        |&(x, y)| image.get_pixel(x, y).data[0] >= 128,
+    );
+
+    result.map(|(path, _)| *path.last().unwrap())
+}
+
+/// A dive frame where the only white pixel is in the opposite corner,
+/// forcing the search to visit every pixel.
+fn dive_frame() -> RgbImage {
+    let mut image = RgbImage::new(1600, 1200);
+    image.put_pixel(1599, 1199, Rgb { data: [255; 3] });
+    image
+}
+
+fn bench_find_point(c: &mut Criterion) {
+    let image = dive_frame();
+
+    let mut group = c.benchmark_group("find_point 1600x1200");
+    group.sample_size(10);
+    group.bench_function("vec neighbours", |b| b.iter(|| find_point_vec((0, 0), &image)));
+    group.bench_function("iterator neighbours", |b| {
+        b.iter(|| find_point((0, 0), &image, |p| p.data[0] >= 128))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_find_point);
+criterion_main!(benches);
@@ -0,0 +1,173 @@
+use core::fmt;
+
+use crate::fractal::Float;
+
+/// How many times the precision of the floats a pixel must span,
+/// leaving room for the errors accumulated while iterating.
+const PRECISION_MARGIN: f64 = 16.0;
+
+/// The error returned when a zoom can not be rendered with the precision used.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ZoomTooDeep {
+    pub zoom: f64,
+    pub max_zoom: f64,
+}
+
+impl fmt::Display for ZoomTooDeep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the zoom {:e} is deeper than the maximum zoom {:e} of the precision used",
+            self.zoom, self.max_zoom
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Camera {
+    pub screen_size: [f64; 2],
+    pub center: [f64; 2],
+    pub zoom: f64,
+}
+
+impl Camera {
+    pub fn new(screen_size: [f64; 2]) -> Camera {
+        Camera { screen_size, center: [0.0, 0.0], zoom: 1.0 }
+    }
+
+    /// Moves the camera center in order to
+    /// keep the given `point` to the same position on screen coordinates.
+    ///
+    /// Useful when you want a user to zoom into a specific area
+    /// without forcing him to change the position of his mouse.
+    ///
+    /// This is the current way Google Map zoom,
+    /// https://stackoverflow.com/questions/22427395
+    ///
+    /// `point` is the point in screen coordinates (`[0, width[`).
+    pub fn target_on_screen(&mut self, point: [f64; 2], zoom: f64) -> Result<(), ZoomTooDeep> {
+        let point = self.screen_to_world(point);
+        self.target_on_world(point, zoom)
+    }
+
+    /// Returns an error without moving the camera when the zoom is deeper
+    /// than the maximum zoom of `f64` around the given point.
+    pub fn target_on_world(&mut self, point: [f64; 2], zoom: f64) -> Result<(), ZoomTooDeep> {
+        let max_zoom = Camera { center: point, ..self.clone() }.max_zoom::<f64>();
+        if zoom < max_zoom {
+            return Err(ZoomTooDeep { zoom, max_zoom });
+        }
+
+        let [cx, cy] = self.center;
+        let [x, y] = point;
+
+        // https://stackoverflow.com/a/22700814/1941280
+        //
+        // move the center keeping the screen distance the same between
+        // the center and the targeted point:
+        //
+        // `cx - x` translate the point to the center
+        // `* zoom` reduce this to the current zoom
+        // `+ x` translate it back to the original position w/o zoom
+        let cx = (cx - x) * zoom + x;
+        let cy = (cy - y) * zoom + y;
+
+        self.zoom = zoom;
+        self.center = [cx, cy];
+
+        Ok(())
+    }
+
+    /// The deepest zoom that can be rendered using `T` floats around the camera center.
+    ///
+    /// The zoom is the scale of the view, so the deepest zoom is the smallest value:
+    /// below it, neighbouring pixels can not be told apart anymore.
+    pub fn max_zoom<T: Float>(&self) -> f64 {
+        let [_, sy] = self.screen_size;
+        let [cx, cy] = self.center;
+
+        let epsilon = T::epsilon().to_f64().unwrap_or(core::f64::EPSILON);
+        let magnitude = cx.abs().max(cy.abs()).max(2.0);
+
+        // a pixel spans `2 * zoom / sy` in world coordinates
+        PRECISION_MARGIN * epsilon * magnitude * sy / 2.0
+    }
+
+    /// Transforms the point in screen coordinates in a point in world coordinates,
+    /// taking the screen data and the zoom into account.
+    ///
+    /// `point` is the point in screen coordinates (`[0, width[`).
+    pub fn screen_to_world(&self, point: [f64; 2]) -> [f64; 2] {
+        let [sx, sy] = self.screen_size;
+        let [cx, cy] = self.center;
+        let [x, y] = point;
+
+        assert!(x >= 0.0 && x < sx, "x is outside screen domain coordinates");
+        assert!(y >= 0.0 && y < sy, "y is outside screen domain coordinates");
+
+        let screen_ratio = sx / sy;
+
+        // reduce the point to world coordinates:
+        //
+        // `* 2.0` move to `[0, 2 * width[`
+        // `x / sx` reduce to `[0, 2[`
+        // `- 1.0` move to `[-1, 1[`
+        // `* screen_ratio` keep the screen ratio
+        // `* self.zoom` reduce to the current zoom
+        // `+ cx` translate the point to the center
+        let x = (x * 2.0 / sx - 1.0) * screen_ratio * self.zoom + cx;
+        let y = (y * 2.0 / sy - 1.0) * self.zoom + cy;
+
+        [x, y]
+    }
+
+    /// Transforms the point in world coordinates in a point in screen coordinates,
+    /// this is the inverse of `screen_to_world`.
+    ///
+    /// The returned point can be outside of the screen domain coordinates.
+    pub fn world_to_screen(&self, point: [f64; 2]) -> [f64; 2] {
+        let [sx, sy] = self.screen_size;
+        let [cx, cy] = self.center;
+        let [x, y] = point;
+
+        let screen_ratio = sx / sy;
+
+        let x = ((x - cx) / (screen_ratio * self.zoom) + 1.0) * sx / 2.0;
+        let y = ((y - cy) / self.zoom + 1.0) * sy / 2.0;
+
+        [x, y]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn too_deep_does_not_move() {
+        let mut camera = Camera { center: [-0.75, 0.1], ..Camera::new([64.0, 48.0]) };
+        let point = [-0.7, 0.2];
+        let max_zoom = Camera { center: point, ..camera.clone() }.max_zoom::<f64>();
+
+        let zoom = max_zoom * 0.99;
+        assert_eq!(camera.target_on_world(point, zoom), Err(ZoomTooDeep { zoom, max_zoom }));
+        assert_eq!(camera.center, [-0.75, 0.1]);
+        assert_eq!(camera.zoom, 1.0);
+    }
+
+    #[test]
+    fn just_inside_limit() {
+        let mut camera = Camera { center: [-0.75, 0.1], ..Camera::new([64.0, 48.0]) };
+        let point = [-0.7, 0.2];
+        let max_zoom = Camera { center: point, ..camera.clone() }.max_zoom::<f64>();
+
+        assert_eq!(camera.target_on_world(point, max_zoom), Ok(()));
+        assert_eq!(camera.zoom, max_zoom);
+    }
+
+    #[test]
+    fn single_precision_is_shallower() {
+        let camera = Camera::new([64.0, 48.0]);
+        assert!(camera.max_zoom::<f32>() > camera.max_zoom::<f64>());
+    }
+}
@@ -0,0 +1,53 @@
+use core::fmt;
+
+/// An optional part of the crate, which may have been compiled out.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Capability {
+    /// Everything requiring the standard library: images, generation and seeding.
+    Std,
+    /// The preview window shown while diving.
+    Gui,
+}
+
+/// The error returned when a capability that has been compiled out is required.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MissingCapability(pub Capability);
+
+impl Capability {
+    pub fn all() -> &'static [Capability] {
+        &[Capability::Std, Capability::Gui]
+    }
+
+    /// The cargo feature enabling this capability.
+    pub fn feature(&self) -> &'static str {
+        match self {
+            Capability::Std => "std",
+            Capability::Gui => "gui",
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            Capability::Std => cfg!(feature = "std"),
+            Capability::Gui => cfg!(feature = "gui"),
+        }
+    }
+
+    pub fn require(&self) -> Result<(), MissingCapability> {
+        if self.is_enabled() {
+            Ok(())
+        } else {
+            Err(MissingCapability(*self))
+        }
+    }
+}
+
+impl fmt::Display for MissingCapability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "this option requires frustalz to be rebuilt with the \"{}\" feature",
+            self.0.feature()
+        )
+    }
+}
@@ -0,0 +1,45 @@
+use crate::fractal::{Float, Fractal, Orbit};
+use alloc::vec::Vec;
+use num_complex::Complex;
+use num_traits::NumCast;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Julia<T = f64> {
+    c: Complex<T>,
+}
+
+impl<T: Float> Julia<T> {
+    pub fn new(re: T, im: T) -> Julia<T> {
+        Julia { c: Complex::new(re, im) }
+    }
+}
+
+impl<T: Float> Fractal<T> for Julia<T> {
+    fn iterations(&self, x: T, y: T) -> u8 {
+        let four: T = NumCast::from(4.0).unwrap();
+        let mut iterations = 0;
+        let mut z = Complex::new(x, y);
+
+        while (z + z).re <= four && iterations < u8::max_value() {
+            z = z * z + self.c;
+            iterations += 1;
+        }
+
+        iterations
+    }
+}
+
+impl<T: Float> Orbit<T> for Julia<T> {
+    fn orbit(&self, x: T, y: T) -> Vec<Complex<T>> {
+        let four: T = NumCast::from(4.0).unwrap();
+        let mut z = Complex::new(x, y);
+        let mut orbit = alloc::vec![z];
+
+        while (z + z).re <= four && orbit.len() <= u8::max_value() as usize {
+            z = z * z + self.c;
+            orbit.push(z);
+        }
+
+        orbit
+    }
+}
@@ -0,0 +1,45 @@
+use crate::fractal::{Float, Fractal, Orbit};
+use alloc::vec::Vec;
+use num_complex::Complex;
+use num_traits::NumCast;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Mandelbrot;
+
+impl Mandelbrot {
+    pub fn new() -> Mandelbrot {
+        Mandelbrot
+    }
+}
+
+impl<T: Float> Fractal<T> for Mandelbrot {
+    fn iterations(&self, x: T, y: T) -> u8 {
+        let four: T = NumCast::from(4.0).unwrap();
+        let mut iterations = 0;
+        let c = Complex::new(x, y);
+        let mut z = c;
+
+        while (z * z).re <= four && iterations < u8::max_value() {
+            z = z * z + c;
+            iterations += 1;
+        }
+
+        iterations
+    }
+}
+
+impl<T: Float> Orbit<T> for Mandelbrot {
+    fn orbit(&self, x: T, y: T) -> Vec<Complex<T>> {
+        let four: T = NumCast::from(4.0).unwrap();
+        let c = Complex::new(x, y);
+        let mut z = c;
+        let mut orbit = alloc::vec![z];
+
+        while (z * z).re <= four && orbit.len() <= u8::max_value() as usize {
+            z = z * z + c;
+            orbit.push(z);
+        }
+
+        orbit
+    }
+}
@@ -0,0 +1,30 @@
+mod julia;
+mod mandelbrot;
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::ops::Deref;
+use num_complex::Complex;
+
+pub use self::julia::Julia;
+pub use self::mandelbrot::Mandelbrot;
+
+/// The floating point types fractals can be computed with,
+/// lower precisions are faster but do not allow to zoom as deep.
+pub use num_traits::float::FloatCore as Float;
+
+pub trait Fractal<T: Float = f64> {
+    fn iterations(&self, x: T, y: T) -> u8;
+}
+
+/// Fractals able to expose the successive values taken by `z` for a point.
+pub trait Orbit<T: Float = f64>: Fractal<T> {
+    /// Returns the values of `z` until it escapes or the iterations limit is reached.
+    fn orbit(&self, x: T, y: T) -> Vec<Complex<T>>;
+}
+
+impl<T: Float, F: Fractal<T> + ?Sized> Fractal<T> for Box<F> {
+    fn iterations(&self, x: T, y: T) -> u8 {
+        self.deref().iterations(x, y)
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use image::RgbImage;
+
+/// Receives the images produced while diving into a fractal.
+pub trait DebugSink: fmt::Debug {
+    /// Whether the images are worth being produced for this sink.
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    /// Receives the image identified by `name` (e.g. `spotted-area-003-007`).
+    fn image(&mut self, name: &str, image: &RgbImage);
+}
+
+/// Writes the images as `<name>.png` files into a directory.
+#[derive(Debug, Clone)]
+pub struct FileSink {
+    dir: PathBuf,
+}
+
+impl FileSink {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> FileSink {
+        FileSink { dir: dir.into() }
+    }
+}
+
+impl DebugSink for FileSink {
+    fn image(&mut self, name: &str, image: &RgbImage) {
+        let path = self.dir.join(format!("{}.png", name));
+        if let Err(e) = image.save(&path) {
+            eprintln!("can not save debug image to {:?}: {}", path, e);
+        }
+    }
+}
+
+/// Keeps the images in memory, clones share the same images.
+#[derive(Debug, Default, Clone)]
+pub struct MemorySink {
+    images: Arc<Mutex<Vec<(String, RgbImage)>>>,
+}
+
+impl MemorySink {
+    pub fn new() -> MemorySink {
+        MemorySink::default()
+    }
+
+    /// Returns the names and images received until now.
+    pub fn images(&self) -> Vec<(String, RgbImage)> {
+        self.images.lock().unwrap().clone()
+    }
+}
+
+impl DebugSink for MemorySink {
+    fn image(&mut self, name: &str, image: &RgbImage) {
+        self.images.lock().unwrap().push((name.to_string(), image.clone()));
+    }
+}
+
+/// Drops the images, they are not even produced.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct NullSink;
+
+impl DebugSink for NullSink {
+    fn is_enabled(&self) -> bool {
+        false
+    }
+
+    fn image(&mut self, _name: &str, _image: &RgbImage) {}
+}
@@ -0,0 +1,66 @@
+use crate::camera::Camera;
+use crate::generate::FractalType;
+use serde_derive::{Deserialize, Serialize};
+
+/// A point found while searching for a target, with the score it was chosen by.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct Candidate {
+    pub point: (u32, u32),
+    /// The number of pixels walked to reach the point from the previous one,
+    /// the search keeps the candidate with the lowest score.
+    pub score: usize,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Decision {
+    /// The target has been found by the heuristic.
+    Automatic,
+    /// The target has been chosen by the user.
+    Steered,
+    /// No target could be found, the dive stopped there.
+    Stopped,
+}
+
+/// Everything considered to choose the next target point,
+/// positions are in screen coordinates of the camera at this step.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiveStep {
+    pub center: [f64; 2],
+    pub zoom: f64,
+    pub start: Option<(u32, u32)>,
+    pub black_point: Option<Candidate>,
+    pub edge_point: Option<Candidate>,
+    pub target: Option<(u32, u32)>,
+    pub decision: Decision,
+}
+
+impl DiveStep {
+    pub fn steered(camera: &Camera, target: (u32, u32)) -> DiveStep {
+        DiveStep {
+            center: camera.center,
+            zoom: camera.zoom,
+            start: None,
+            black_point: None,
+            edge_point: None,
+            target: Some(target),
+            decision: Decision::Steered,
+        }
+    }
+
+    pub fn camera(&self, dimensions: (u32, u32)) -> Camera {
+        let (width, height) = dimensions;
+        Camera { screen_size: [width as f64, height as f64], center: self.center, zoom: self.zoom }
+    }
+}
+
+/// The decisions taken while diving into a fractal, can be replayed later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiveTrace {
+    pub fractal_type: FractalType,
+    pub domain: [f64; 2],
+    pub dimensions: (u32, u32),
+    pub steps: Vec<DiveStep>,
+    /// Why the dive stopped before its last zoom step, if it did.
+    #[serde(default)]
+    pub stop_reason: Option<String>,
+}
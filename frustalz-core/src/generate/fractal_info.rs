@@ -8,6 +8,8 @@ pub struct FractalInfo {
     pub domain: Complex64,
     pub position: [f64; 2],
     pub zoom: f64,
+    /// Why the dive stopped before its last zoom step, if it did.
+    pub stop_reason: Option<String>,
 }
 
 impl fmt::Display for FractalInfo {
@@ -41,3 +43,34 @@ impl fmt::Display for FractalInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn julia_caption() {
+        let info = FractalInfo {
+            fractal_type: FractalType::Julia,
+            domain: Complex64::new(-0.8, 0.156),
+            position: [0.25, -0.5],
+            zoom: 0.01,
+            stop_reason: None,
+        };
+
+        insta::assert_snapshot!(info.to_string(), @"Here is the julia fractal in the domain (-0.800, 0.156), focus is on the area (0.250, -0.500) with the zoom set on 100.000x.");
+    }
+
+    #[test]
+    fn mandelbrot_caption() {
+        let info = FractalInfo {
+            fractal_type: FractalType::Mandelbrot,
+            domain: Complex64::new(0.0, 0.0),
+            position: [-0.75, 0.1],
+            zoom: 0.5,
+            stop_reason: None,
+        };
+
+        insta::assert_snapshot!(info.to_string(), @"Here is the mandelbrot fractal, focus is on the area (-0.750, 0.100) with the zoom set on 2.000x.");
+    }
+}
@@ -0,0 +1,667 @@
+mod debug_sink;
+mod dive_trace;
+mod fractal_info;
+mod quality;
+mod stop_criteria;
+
+pub use self::debug_sink::{DebugSink, FileSink, MemorySink, NullSink};
+pub use self::dive_trace::{Candidate, Decision, DiveStep, DiveTrace};
+pub use self::fractal_info::FractalInfo;
+pub use self::quality::Quality;
+pub use self::stop_criteria::{DiveStatistics, StopCriteria};
+
+use crate::camera::{Camera, ZoomTooDeep};
+use crate::fractal::{Fractal, Julia, Mandelbrot, Orbit};
+use crate::image::{chromatic_aberration, edges, film_grain, kaleidoscope};
+use crate::image::{make_seamless, produce_image, retro, zoom_strip};
+use crate::image::{produce_image_with_progress, produce_orbit_image_with_progress};
+use crate::image::{Antialiazing, Coloring, ComplexPalette, RetroPalette, ScreenDimensions};
+use crate::image::{GrainStrength, SubGradient, ToneMapping};
+#[cfg(feature = "gui")]
+use crate::preview::Preview;
+use crate::status::StatusFile;
+use image::{imageops, Rgb, RgbImage};
+use num_complex::Complex64;
+use palette::rgb::LinSrgb;
+use palette::Gradient;
+use pathfinding::dijkstra::dijkstra;
+use rand::distributions::{IndependentSample, Range};
+use rand::Rng;
+use rand_derive::Rand;
+use serde_derive::{Deserialize, Serialize};
+use std::fs::File;
+use std::io;
+use std::num::NonZeroU32;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+fn report(status_file: Option<&StatusFile>, stage: &str, percent: f64) {
+    if let Some(status_file) = status_file {
+        if let Err(e) = status_file.update(stage, percent) {
+            eprintln!("can not update the status file: {}", e);
+        }
+    }
+}
+
+/// Zooms the dive camera toward the given world point, the final shot is rendered
+/// with more pixels than the dive so the zoom must also be reachable with its
+/// supersampled `shot_size`, the camera does not move otherwise.
+fn target_dive_and_shot(
+    camera: &mut Camera,
+    shot_size: [f64; 2],
+    point: [f64; 2],
+    zoom: f64,
+) -> Result<(), ZoomTooDeep> {
+    Camera { screen_size: shot_size, ..camera.clone() }
+        .target_on_world(point, zoom)
+        .and_then(|_| camera.target_on_world(point, zoom))
+}
+
+/// The direct neighbours of a pixel, without allocating.
+fn neighbours(
+    point: (u32, u32),
+    dimensions: (u32, u32),
+) -> impl Iterator<Item = ((u32, u32), usize)> {
+    let (x, y) = point;
+    let (width, height) = dimensions;
+
+    let left = if x > 0 { Some((x - 1, y)) } else { None };
+    let up = if y > 0 { Some((x, y - 1)) } else { None };
+    let right = if x < width - 1 { Some((x + 1, y)) } else { None };
+    let down = if y < height - 1 { Some((x, y + 1)) } else { None };
+
+    left.into_iter().chain(up).chain(right).chain(down).map(|p| (p, 1))
+}
+
+/// Finds the nearest pixel from `start` that satisfies the predicate.
+pub fn find_point<P>(start: (u32, u32), image: &RgbImage, predicate: P) -> Option<Candidate>
+where
+    P: Fn(&Rgb<u8>) -> bool,
+{
+    let dimensions = image.dimensions();
+
+    let result = dijkstra(
+        &start,
+        |&point| neighbours(point, dimensions),
+        |&(x, y)| predicate(&image.get_pixel(x, y)),
+    );
+
+    result.map(|(path, score)| Candidate { point: *path.last().unwrap(), score })
+}
+
+#[derive(Debug, Copy, Clone, Rand, Serialize, Deserialize)]
+pub enum FractalType {
+    Julia,
+    Mandelbrot,
+}
+
+impl FromStr for FractalType {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "julia" => Ok(FractalType::Julia),
+            "mandelbrot" => Ok(FractalType::Mandelbrot),
+            _ => Err("invalid fractal type, expected \"julia\" or \"mandelbrot\""),
+        }
+    }
+}
+
+/// Find a good target point that will not be a black area:
+///   - create a grayscale image
+///   - blur the grayscale image
+///   - find the nearest black point
+///   - create an edge image of the first grayscaled image
+///   - find the nearest white point on the edged image starting from the previous black point
+fn find_target_point<F, R>(
+    rng: &mut R,
+    fractal: &F,
+    camera: &Camera,
+    dimensions: (u32, u32),
+) -> DiveStep
+where
+    F: Fractal + Sync,
+    R: Rng,
+{
+    let (width, height) = dimensions;
+
+    let grayscaled = produce_image(fractal, camera, dimensions, None, |i| Rgb { data: [i; 3] });
+    let blurred = imageops::blur(&grayscaled, 10.0);
+    let start = (rng.gen_range(0, width), rng.gen_range(0, height));
+    let black_point = find_point(start, &blurred, |p| p.data[0] <= 128);
+
+    let edge_point = black_point.and_then(|black_point| {
+        let edged = edges(&grayscaled);
+        find_point(black_point.point, &edged, |p| p.data[0] >= 128)
+    });
+
+    let target = edge_point.map(|c| c.point);
+    let decision = if target.is_some() { Decision::Automatic } else { Decision::Stopped };
+
+    DiveStep {
+        center: camera.center,
+        zoom: camera.zoom,
+        start: Some(start),
+        black_point,
+        edge_point,
+        target,
+        decision,
+    }
+}
+
+#[derive(Debug)]
+pub struct Generator<R: Rng> {
+    rng: R,
+    dive_dimensions: ScreenDimensions,
+    shot_dimensions: ScreenDimensions,
+    antialiazing: Antialiazing,
+    debug_sink: Box<dyn DebugSink>,
+    dive_trace: Option<PathBuf>,
+    zoom_strip: Option<NonZeroU32>,
+    kaleidoscope: Option<NonZeroU32>,
+    seamless: Option<u32>,
+    film_grain: Option<GrainStrength>,
+    chromatic_aberration: Option<f64>,
+    julia_companion: bool,
+    tone_mapping: Option<ToneMapping>,
+    retro: Option<RetroPalette>,
+    coloring: Option<Coloring>,
+    status_file: Option<StatusFile>,
+    stop_criteria: StopCriteria,
+    #[cfg(feature = "gui")]
+    preview: bool,
+}
+
+impl<R: Rng> Generator<R> {
+    pub fn new(rng: R) -> Self {
+        let quality = Quality::default();
+        Self {
+            rng: rng,
+            dive_dimensions: quality.dive_dimensions(),
+            shot_dimensions: quality.shot_dimensions(),
+            antialiazing: quality.antialiazing(),
+            debug_sink: Box::new(FileSink::new(".")),
+            dive_trace: None,
+            zoom_strip: None,
+            kaleidoscope: None,
+            seamless: None,
+            film_grain: None,
+            chromatic_aberration: None,
+            julia_companion: false,
+            tone_mapping: None,
+            retro: None,
+            coloring: None,
+            status_file: None,
+            stop_criteria: StopCriteria::default(),
+            #[cfg(feature = "gui")]
+            preview: false,
+        }
+    }
+
+    /// Sets the dimensions and antialiazing of the given preset.
+    pub fn quality(&mut self, quality: Quality) -> &mut Self {
+        self.dive_dimensions = quality.dive_dimensions();
+        self.shot_dimensions = quality.shot_dimensions();
+        self.antialiazing = quality.antialiazing();
+        self
+    }
+
+    pub fn dive_dimensions(&mut self, dimensions: ScreenDimensions) -> &mut Self {
+        self.dive_dimensions = dimensions;
+        self
+    }
+
+    pub fn shot_dimensions(&mut self, dimensions: ScreenDimensions) -> &mut Self {
+        self.shot_dimensions = dimensions;
+        self
+    }
+
+    pub fn antialiazing(&mut self, antialiazing: Antialiazing) -> &mut Self {
+        self.antialiazing = antialiazing;
+        self
+    }
+
+    /// Writes the images produced while diving into the current directory,
+    /// or drops them.
+    pub fn debug_images(&mut self, debug_images: bool) -> &mut Self {
+        if debug_images {
+            self.debug_sink(FileSink::new("."))
+        } else {
+            self.debug_sink(NullSink)
+        }
+    }
+
+    /// Sends the images produced while diving to the given sink.
+    pub fn debug_sink<S: DebugSink + 'static>(&mut self, sink: S) -> &mut Self {
+        self.debug_sink = Box::new(sink);
+        self
+    }
+
+    /// Records every candidate point and decision taken while diving
+    /// and writes them to the given file at the end of the generation.
+    pub fn dive_trace(&mut self, path: Option<PathBuf>) -> &mut Self {
+        self.dive_trace = path;
+        self
+    }
+
+    /// Replaces the final shot by a strip of `levels` shots of the final center,
+    /// going from the whole fractal to the final zoom.
+    pub fn zoom_strip(&mut self, levels: Option<NonZeroU32>) -> &mut Self {
+        self.zoom_strip = levels;
+        self
+    }
+
+    /// Mirrors the final shot `folds` times around the targeted point.
+    pub fn kaleidoscope(&mut self, folds: Option<NonZeroU32>) -> &mut Self {
+        self.kaleidoscope = folds;
+        self
+    }
+
+    /// Makes the final shot tileable by blending this many pixels of its edges.
+    pub fn seamless(&mut self, blend: Option<u32>) -> &mut Self {
+        self.seamless = blend;
+        self
+    }
+
+    /// Adds a seeded film grain of the given strength to the final shot.
+    pub fn film_grain(&mut self, strength: Option<GrainStrength>) -> &mut Self {
+        self.film_grain = strength;
+        self
+    }
+
+    /// Shifts the red and blue channels of the final shot up to this many pixels.
+    pub fn chromatic_aberration(&mut self, shift: Option<f64>) -> &mut Self {
+        self.chromatic_aberration = shift;
+        self
+    }
+
+    /// While diving into a mandelbrot fractal, sends to the debug sink side by side images
+    /// of the current view and of the julia fractal whose domain is the camera center.
+    ///
+    /// Nothing is produced when the debug sink is disabled, e.g. with `debug_images(false)`.
+    pub fn julia_companion(&mut self, julia_companion: bool) -> &mut Self {
+        self.julia_companion = julia_companion;
+        self
+    }
+
+    /// Applies a tone mapping curve to the colors of the rendered images.
+    pub fn tone_mapping(&mut self, tone_mapping: Option<ToneMapping>) -> &mut Self {
+        self.tone_mapping = tone_mapping;
+        self
+    }
+
+    /// Reduces the final shot to the colors of a retro palette, with dithering.
+    pub fn retro(&mut self, palette: Option<RetroPalette>) -> &mut Self {
+        self.retro = palette;
+        self
+    }
+
+    /// Paints the final shot from the orbits of the points instead of their iterations,
+    /// the zoom strip ignores it and is always painted from the iterations.
+    pub fn coloring(&mut self, coloring: Option<Coloring>) -> &mut Self {
+        self.coloring = coloring;
+        self
+    }
+
+    /// Reports the generation progress in the given status file.
+    pub fn status_file(&mut self, status_file: Option<StatusFile>) -> &mut Self {
+        self.status_file = status_file;
+        self
+    }
+
+    /// Stops the dive early when the statistics of the dive images meet the criteria.
+    pub fn stop_criteria(&mut self, stop_criteria: StopCriteria) -> &mut Self {
+        self.stop_criteria = stop_criteria;
+        self
+    }
+
+    /// Opens a window showing each dive step and the final render,
+    /// closing it or pressing escape stops the dive, pressing space pauses it
+    /// and lets the user click on the next target point.
+    #[cfg(feature = "gui")]
+    pub fn preview(&mut self, preview: bool) -> &mut Self {
+        self.preview = preview;
+        self
+    }
+
+    pub fn generate(mut self) -> (FractalInfo, RgbImage) {
+        let dimensions = self.dive_dimensions.as_tuple();
+        let antialiazing: u32 = self.antialiazing.into();
+
+        let (width, height) = dimensions;
+        let mut camera = Camera::new([width as f64, height as f64]);
+
+        let fractal: Box<dyn Orbit + Sync>;
+        let fractal_type;
+        let domain;
+        let zoom_steps;
+
+        match self.rng.gen() {
+            FractalType::Julia => {
+                // https://upload.wikimedia.org/wikipedia/commons/a/a9/Julia-Teppich.png
+                // http://www.karlsims.com/julia.html
+                let sub_gradients = Gradient::new(vec![
+                    SubGradient::new(
+                        ComplexPalette::new(-0.8, 0.3),
+                        ComplexPalette::new(-0.8, 0.15),
+                    ),
+                    SubGradient::new(
+                        ComplexPalette::new(-0.6, 0.7),
+                        ComplexPalette::new(-0.6, 0.5),
+                    ),
+                    SubGradient::new(
+                        ComplexPalette::new(-0.4, 0.65),
+                        ComplexPalette::new(-0.4, 0.6),
+                    ),
+                    SubGradient::new(
+                        ComplexPalette::new(-0.2, 0.9),
+                        ComplexPalette::new(-0.2, 0.8),
+                    ),
+                    SubGradient::new(ComplexPalette::new(0.0, 1.0), ComplexPalette::new(0.0, 0.7)),
+                    SubGradient::new(
+                        ComplexPalette::new(0.19, 0.6),
+                        ComplexPalette::new(0.19, 0.552),
+                    ),
+                    SubGradient::new(
+                        ComplexPalette::new(0.28, 0.01),
+                        ComplexPalette::new(0.28, -0.01),
+                    ),
+                    SubGradient::new(
+                        ComplexPalette::new(0.29, 0.6),
+                        ComplexPalette::new(0.29, 0.55),
+                    ),
+                ]);
+
+                let sub_gradient = sub_gradients.get(self.rng.gen());
+                let gradient = sub_gradient.gradient();
+                let ComplexPalette(Complex64 { re, im }) = gradient.get(self.rng.gen());
+
+                fractal = Box::new(Julia::new(re, im));
+                fractal_type = FractalType::Julia;
+                domain = Complex64::new(re, im);
+                zoom_steps = self.rng.gen_range(0, 44);
+            }
+            FractalType::Mandelbrot => {
+                fractal = Box::new(Mandelbrot::new());
+                fractal_type = FractalType::Mandelbrot;
+                domain = Complex64::new(0.0, 0.0);
+                zoom_steps = self.rng.gen_range(20, 44);
+            }
+        };
+
+        let zoom_distr = Range::new(0.93, 0.97);
+        let gradient = Gradient::with_domain(vec![
+            (0.0, LinSrgb::new(0.0, 0.027, 0.392)),   // 0,    2.7,  39.2
+            (0.16, LinSrgb::new(0.125, 0.42, 0.796)), // 12.5, 42,   79.6
+            (0.42, LinSrgb::new(0.929, 1.0, 1.0)),    // 92.9, 100,  100
+            (0.6425, LinSrgb::new(1.0, 0.667, 0.0)),  // 100,  66.7, 0
+            (0.8575, LinSrgb::new(0.0, 0.008, 0.0)),  // 0,    0.8,  0
+            (1.0, LinSrgb::new(0.0, 0.0, 0.0)),       // 0,    0,    0
+        ]);
+
+        let tone_mapping = self.tone_mapping;
+        let painter = |i: u8| {
+            let color = gradient.get(i as f32 / 255.0);
+            let color = match tone_mapping {
+                Some(tone_mapping) => tone_mapping.apply(color),
+                None => color,
+            };
+            Rgb { data: color.into_pixel() }
+        };
+
+        #[cfg(feature = "gui")]
+        let mut preview = if self.preview {
+            // the dive must not fail because no display is available
+            match Preview::new("frustalz", dimensions) {
+                Ok(preview) => Some(preview),
+                Err(e) => {
+                    eprintln!("can not open the preview window, continuing without it: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        // to zoom into the fractal:
+        //   - find a good target point using the current camera
+        //   - zoom using the camera into the current image
+        //   - repeat the first step until the max number of iteration is reached,
+        //     a target point can't be found or the stop criteria are met
+        let (shot_width, shot_height) = self.shot_dimensions.as_tuple();
+        let shot_size = [(shot_width * antialiazing) as f64, (shot_height * antialiazing) as f64];
+
+        let mut steps = Vec::new();
+        let mut statistics: Option<DiveStatistics> = None;
+        let mut stop_reason = None;
+        for i in 0..zoom_steps {
+            report(self.status_file.as_ref(), "diving", i as f64 * 100.0 / zoom_steps as f64);
+
+            #[cfg(feature = "gui")]
+            let steered = preview.as_mut().and_then(Preview::take_target);
+            #[cfg(not(feature = "gui"))]
+            let steered = None;
+
+            if steered.is_none() && self.stop_criteria.is_enabled() {
+                let current = DiveStatistics::new(&fractal, &camera, dimensions);
+                if let Some(reason) =
+                    self.stop_criteria.reason_to_stop(statistics.as_ref(), &current)
+                {
+                    stop_reason = Some(format!("dive stopped at step {}: {}", i, reason));
+                    break;
+                }
+                statistics = Some(current);
+            }
+
+            let step = match steered {
+                Some(target) => DiveStep::steered(&camera, target),
+                None => find_target_point(&mut self.rng, &fractal, &camera, dimensions),
+            };
+
+            let target = step.target;
+            steps.push(step);
+
+            match target {
+                Some((x, y)) => {
+                    let [cx, cy] = camera.center;
+                    let [x, y] = camera.screen_to_world([x as f64, y as f64]);
+
+                    let mut too_deep = false;
+                    for n in 0..10 {
+                        let zoom_multiplier = zoom_distr.ind_sample(&mut self.rng);
+                        let zoom = camera.zoom * zoom_multiplier;
+
+                        let t = n as f64 / 10.0;
+                        let x = cx + t * (x - cx);
+                        let y = cy + t * (y - cy);
+
+                        let result = target_dive_and_shot(&mut camera, shot_size, [x, y], zoom);
+                        if let Err(e) = result {
+                            stop_reason = Some(format!("dive stopped at step {}: {}", i, e));
+                            too_deep = true;
+                            break;
+                        }
+
+                        if self.debug_sink.is_enabled() {
+                            let image = produce_image(&fractal, &camera, dimensions, None, |i| {
+                                Rgb { data: [i; 3] }
+                            });
+                            let name = format!("spotted-area-{:03}-{:03}", i, n);
+                            self.debug_sink.image(&name, &edges(&image));
+                        }
+
+                        if self.julia_companion && self.debug_sink.is_enabled() {
+                            if let FractalType::Mandelbrot = fractal_type {
+                                let [re, im] = camera.center;
+                                let julia = Julia::new(re, im);
+                                let whole = Camera::new(camera.screen_size);
+
+                                let (width, height) = dimensions;
+                                let mut frame = RgbImage::new(width * 2, height);
+                                let left =
+                                    produce_image(&fractal, &camera, dimensions, None, &painter);
+                                let right =
+                                    produce_image(&julia, &whole, dimensions, None, &painter);
+                                imageops::replace(&mut frame, &left, 0, 0);
+                                imageops::replace(&mut frame, &right, width, 0);
+
+                                let name = format!("julia-companion-{:03}-{:03}", i, n);
+                                self.debug_sink.image(&name, &frame);
+                            }
+                        }
+
+                        #[cfg(feature = "gui")]
+                        {
+                            if let Some(preview) = &mut preview {
+                                let image =
+                                    produce_image(&fractal, &camera, dimensions, None, &painter);
+                                preview.show(&image);
+                                preview.wait_while_paused();
+
+                                // a target chosen by the user overrides the current one
+                                if !preview.is_open() || preview.has_target() {
+                                    break;
+                                }
+                            }
+                        }
+                    }
+
+                    if too_deep {
+                        break;
+                    }
+
+                    #[cfg(feature = "gui")]
+                    {
+                        if preview.as_ref().map_or(false, |p| !p.is_open()) {
+                            break;
+                        }
+                    }
+                }
+                None => break,
+            }
+        }
+
+        let dimensions = self.shot_dimensions.as_tuple();
+        let status_file = self.status_file.as_ref();
+        report(status_file, "rendering", 0.0);
+
+        // show a fast render while the antialiazed one is being produced
+        #[cfg(feature = "gui")]
+        {
+            if let Some(preview) = &mut preview {
+                preview.show(&produce_image(&fractal, &camera, dimensions, None, &painter));
+            }
+        }
+
+        // the final shot is the longest stage, its progress is reported row by row
+        let (coloring, aa) = (self.coloring, Some(antialiazing));
+        let progress = |done: f64| report(status_file, "rendering", done * 100.0);
+        let shot = |camera: &Camera, dimensions: (u32, u32)| match coloring {
+            Some(coloring) => {
+                let painter = |orbit: &[Complex64]| painter(coloring.index(orbit));
+                produce_orbit_image_with_progress(
+                    &*fractal, camera, dimensions, aa, painter, progress,
+                )
+            }
+            None => {
+                produce_image_with_progress(&fractal, camera, dimensions, aa, &painter, progress)
+            }
+        };
+
+        let image = match (self.zoom_strip, self.seamless) {
+            (Some(levels), _) => {
+                zoom_strip(&fractal, &camera, dimensions, levels, Some(antialiazing), &painter)
+            }
+            (None, Some(blend)) => {
+                // render a larger area at the same scale to have pixels to blend,
+                // moved for the kept top-left part to stay centered on the target
+                let (width, height) = dimensions;
+                let dimensions = (width + blend, height + blend);
+                let zoom = camera.zoom * (height + blend) as f64 / height as f64;
+                let shift = camera.zoom * blend as f64 / height as f64;
+                let [cx, cy] = camera.center;
+                let camera = Camera { zoom, center: [cx + shift, cy + shift], ..camera.clone() };
+
+                make_seamless(&shot(&camera, dimensions), blend)
+            }
+            (None, None) => shot(&camera, dimensions),
+        };
+
+        let image = match self.kaleidoscope {
+            Some(folds) => kaleidoscope(&image, folds),
+            None => image,
+        };
+
+        let mut image = match self.chromatic_aberration {
+            Some(shift) => chromatic_aberration(&image, shift),
+            None => image,
+        };
+
+        if let Some(strength) = self.film_grain {
+            film_grain(&mut image, &mut self.rng, strength.into());
+        }
+
+        if let Some(palette) = self.retro {
+            retro(&mut image, palette);
+        }
+
+        #[cfg(feature = "gui")]
+        {
+            if let Some(preview) = &mut preview {
+                preview.show(&image);
+            }
+        }
+
+        if let Some(path) = &self.dive_trace {
+            let trace = DiveTrace {
+                fractal_type,
+                domain: [domain.re, domain.im],
+                dimensions: self.dive_dimensions.as_tuple(),
+                steps,
+                stop_reason: stop_reason.clone(),
+            };
+            let result = File::create(path).and_then(|file| {
+                serde_json::to_writer_pretty(file, &trace).map_err(io::Error::from)
+            });
+            if let Err(e) = result {
+                eprintln!("can not write the dive trace to {:?}: {}", path, e);
+            }
+        }
+
+        report(self.status_file.as_ref(), "rendered", 100.0);
+
+        let info = FractalInfo {
+            fractal_type,
+            domain,
+            position: camera.center,
+            zoom: camera.zoom,
+            stop_reason,
+        };
+
+        (info, image)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shot_size_guard() {
+        let mut camera = Camera::new([24.0, 16.0]);
+        let shot_size = [32.0 * 16.0, 24.0 * 16.0];
+
+        // reachable with the dive height but not with the supersampled shot height
+        let shot_max_zoom = Camera::new(shot_size).max_zoom::<f64>();
+        let zoom = shot_max_zoom / 2.0;
+        assert!(zoom >= camera.max_zoom::<f64>());
+
+        let result = target_dive_and_shot(&mut camera, shot_size, [0.0, 0.0], zoom);
+        assert_eq!(result, Err(ZoomTooDeep { zoom, max_zoom: shot_max_zoom }));
+        assert_eq!(camera.zoom, 1.0);
+
+        let zoom = shot_max_zoom * 2.0;
+        assert_eq!(target_dive_and_shot(&mut camera, shot_size, [0.0, 0.0], zoom), Ok(()));
+        assert_eq!(camera.zoom, zoom);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::image::{Antialiazing, ScreenDimensions};
+use std::str::FromStr;
+
+/// Presets bundling the settings that trade rendering time for quality.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Quality {
+    Draft,
+    Standard,
+    Ultra,
+}
+
+impl Quality {
+    pub fn antialiazing(&self) -> Antialiazing {
+        let value = match self {
+            Quality::Draft => 1,
+            Quality::Standard => 4,
+            // the factor applies on both axes, 16 would supersample
+            // the ultra shot into a buffer of more than 2 GB
+            Quality::Ultra => 4,
+        };
+        Antialiazing::new(value).unwrap()
+    }
+
+    pub fn dive_dimensions(&self) -> ScreenDimensions {
+        match self {
+            Quality::Draft => ScreenDimensions(250, 250),
+            Quality::Standard => ScreenDimensions(500, 500),
+            Quality::Ultra => ScreenDimensions(800, 800),
+        }
+    }
+
+    pub fn shot_dimensions(&self) -> ScreenDimensions {
+        match self {
+            Quality::Draft => ScreenDimensions(400, 300),
+            Quality::Standard => ScreenDimensions(800, 600),
+            Quality::Ultra => ScreenDimensions(1920, 1440),
+        }
+    }
+}
+
+impl Default for Quality {
+    fn default() -> Self {
+        Quality::Standard
+    }
+}
+
+impl FromStr for Quality {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "draft" => Ok(Quality::Draft),
+            "standard" => Ok(Quality::Standard),
+            "ultra" => Ok(Quality::Ultra),
+            _ => Err("invalid quality, expected \"draft\", \"standard\" or \"ultra\""),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preset(quality: Quality) -> (u32, (u32, u32), (u32, u32)) {
+        let antialiazing = quality.antialiazing().into();
+        (antialiazing, quality.dive_dimensions().as_tuple(), quality.shot_dimensions().as_tuple())
+    }
+
+    #[test]
+    fn presets() {
+        assert_eq!(preset(Quality::Draft), (1, (250, 250), (400, 300)));
+        assert_eq!(preset(Quality::Standard), (4, (500, 500), (800, 600)));
+        assert_eq!(preset(Quality::Ultra), (4, (800, 800), (1920, 1440)));
+    }
+}
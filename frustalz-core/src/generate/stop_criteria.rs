@@ -0,0 +1,114 @@
+use image::{Rgb, RgbImage};
+
+use crate::camera::Camera;
+use crate::fractal::Fractal;
+use crate::image::{edges, produce_image};
+
+/// Statistics of the grayscaled image of a dive step.
+#[derive(Debug, Copy, Clone)]
+pub struct DiveStatistics {
+    /// The fraction of pixels lying on an edge.
+    pub edge_density: f64,
+    /// The fraction of pixels that reached the max number of iterations.
+    pub interior_fraction: f64,
+}
+
+fn fraction<P: Fn(u8) -> bool>(image: &RgbImage, predicate: P) -> f64 {
+    let (width, height) = image.dimensions();
+    let count = image.pixels().filter(|p| predicate(p.data[0])).count();
+    count as f64 / (width as f64 * height as f64).max(1.0)
+}
+
+impl DiveStatistics {
+    pub fn new<F>(fractal: &F, camera: &Camera, dimensions: (u32, u32)) -> DiveStatistics
+    where
+        F: Fractal + ?Sized + Sync,
+    {
+        let grayscaled = produce_image(fractal, camera, dimensions, None, |i| Rgb { data: [i; 3] });
+
+        DiveStatistics {
+            edge_density: fraction(&edges(&grayscaled), |v| v >= 128),
+            interior_fraction: fraction(&grayscaled, |v| v == u8::max_value()),
+        }
+    }
+}
+
+/// Stops the dive before the zoom steps are exhausted
+/// when the statistics of the images tell that it is a good framing.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct StopCriteria {
+    /// Stop when the edge density increases by less than this between two steps.
+    pub edge_density_plateau: Option<f64>,
+    /// Stop when the fraction of interior pixels exceeds this.
+    pub interior_fraction: Option<f64>,
+}
+
+impl StopCriteria {
+    pub fn is_enabled(&self) -> bool {
+        self.edge_density_plateau.is_some() || self.interior_fraction.is_some()
+    }
+
+    /// Returns why the dive must stop at the step with the `current` statistics, if it must.
+    pub fn reason_to_stop(
+        &self,
+        previous: Option<&DiveStatistics>,
+        current: &DiveStatistics,
+    ) -> Option<&'static str> {
+        if let Some(threshold) = self.interior_fraction {
+            if current.interior_fraction > threshold {
+                return Some("the interior fraction exceeds the threshold");
+            }
+        }
+
+        if let (Some(plateau), Some(previous)) = (self.edge_density_plateau, previous) {
+            if current.edge_density - previous.edge_density < plateau {
+                return Some("the edge density stopped increasing");
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statistics(edge_density: f64, interior_fraction: f64) -> DiveStatistics {
+        DiveStatistics { edge_density, interior_fraction }
+    }
+
+    #[test]
+    fn disabled() {
+        let criteria = StopCriteria::default();
+        assert!(!criteria.is_enabled());
+
+        let previous = statistics(0.5, 0.0);
+        let current = statistics(0.5, 1.0);
+        assert_eq!(criteria.reason_to_stop(Some(&previous), &current), None);
+    }
+
+    #[test]
+    fn interior_fraction() {
+        let criteria = StopCriteria { interior_fraction: Some(0.5), ..StopCriteria::default() };
+        assert!(criteria.is_enabled());
+
+        assert_eq!(criteria.reason_to_stop(None, &statistics(0.1, 0.4)), None);
+        assert_eq!(criteria.reason_to_stop(None, &statistics(0.1, 0.5)), None);
+        assert!(criteria.reason_to_stop(None, &statistics(0.1, 0.6)).is_some());
+    }
+
+    #[test]
+    fn edge_density_plateau() {
+        let criteria = StopCriteria { edge_density_plateau: Some(0.01), ..StopCriteria::default() };
+        assert!(criteria.is_enabled());
+
+        // the first step has nothing to be compared to
+        assert_eq!(criteria.reason_to_stop(None, &statistics(0.2, 0.0)), None);
+
+        let previous = statistics(0.2, 0.0);
+        assert_eq!(criteria.reason_to_stop(Some(&previous), &statistics(0.25, 0.0)), None);
+        assert!(criteria.reason_to_stop(Some(&previous), &statistics(0.205, 0.0)).is_some());
+        assert!(criteria.reason_to_stop(Some(&previous), &statistics(0.1, 0.0)).is_some());
+    }
+}
@@ -0,0 +1,140 @@
+use image::{Rgb, RgbImage};
+
+fn assert_same_dimensions(a: &RgbImage, b: &RgbImage) {
+    assert_eq!(a.dimensions(), b.dimensions(), "images must have the same dimensions");
+    assert!(a.width() > 0 && a.height() > 0, "images must not be empty");
+}
+
+fn luminance(p: &Rgb<u8>) -> f64 {
+    let [r, g, b] = p.data;
+    0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64
+}
+
+/// Produces an image where identical pixels are black and the most different
+/// ones are white, going through red and yellow.
+pub fn difference_heatmap(a: &RgbImage, b: &RgbImage) -> RgbImage {
+    assert_same_dimensions(a, b);
+
+    let (width, height) = a.dimensions();
+    RgbImage::from_fn(width, height, |x, y| {
+        let pa = a.get_pixel(x, y).data;
+        let pb = b.get_pixel(x, y).data;
+
+        let sum: u32 = pa.iter().zip(&pb).map(|(&a, &b)| (a as i32 - b as i32).abs() as u32).sum();
+        let t = sum as f64 / (3.0 * 255.0);
+
+        // black -> red -> yellow -> white
+        let r = (t * 3.0).min(1.0);
+        let g = (t * 3.0 - 1.0).max(0.0).min(1.0);
+        let b = (t * 3.0 - 2.0).max(0.0).min(1.0);
+
+        Rgb { data: [(r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8] }
+    })
+}
+
+/// The peak signal-to-noise ratio between two images in decibels,
+/// infinite if the images are identical.
+pub fn psnr(a: &RgbImage, b: &RgbImage) -> f64 {
+    assert_same_dimensions(a, b);
+
+    let count = a.len() as f64;
+    let squared_sum: f64 = a
+        .iter()
+        .zip(b.iter())
+        .map(|(&a, &b)| {
+            let d = a as f64 - b as f64;
+            d * d
+        })
+        .sum();
+
+    let mse = squared_sum / count;
+    10.0 * (255.0 * 255.0 / mse).log10()
+}
+
+/// The mean structural similarity of the luminance of two images,
+/// computed over 8x8 windows, `1.0` meaning the images are identical.
+pub fn ssim(a: &RgbImage, b: &RgbImage) -> f64 {
+    assert_same_dimensions(a, b);
+
+    const WINDOW: u32 = 8;
+    let c1 = (0.01 * 255.0f64).powi(2);
+    let c2 = (0.03 * 255.0f64).powi(2);
+
+    let (width, height) = a.dimensions();
+    let mut total = 0.0;
+    let mut windows = 0;
+
+    for wy in (0..height).step_by(WINDOW as usize) {
+        for wx in (0..width).step_by(WINDOW as usize) {
+            let mut pixels = Vec::with_capacity((WINDOW * WINDOW) as usize);
+            for y in wy..(wy + WINDOW).min(height) {
+                for x in wx..(wx + WINDOW).min(width) {
+                    pixels.push((luminance(a.get_pixel(x, y)), luminance(b.get_pixel(x, y))));
+                }
+            }
+
+            let n = pixels.len() as f64;
+            let mean_a = pixels.iter().map(|p| p.0).sum::<f64>() / n;
+            let mean_b = pixels.iter().map(|p| p.1).sum::<f64>() / n;
+
+            let (mut var_a, mut var_b, mut covar) = (0.0, 0.0, 0.0);
+            for (la, lb) in &pixels {
+                var_a += (la - mean_a).powi(2);
+                var_b += (lb - mean_b).powi(2);
+                covar += (la - mean_a) * (lb - mean_b);
+            }
+            var_a /= n;
+            var_b /= n;
+            covar /= n;
+
+            let numerator = (2.0 * mean_a * mean_b + c1) * (2.0 * covar + c2);
+            let denominator = (mean_a.powi(2) + mean_b.powi(2) + c1) * (var_a + var_b + c2);
+
+            total += numerator / denominator;
+            windows += 1;
+        }
+    }
+
+    total / windows as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gradient(offset: u8) -> RgbImage {
+        RgbImage::from_fn(20, 12, |x, y| Rgb {
+            data: [(x * 10) as u8 + offset, (y * 20) as u8 + offset, offset],
+        })
+    }
+
+    #[test]
+    fn identical_images() {
+        let image = gradient(0);
+
+        assert_eq!(psnr(&image, &image), f64::INFINITY);
+        assert!((ssim(&image, &image) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn uniform_offset() {
+        let (a, b) = (gradient(0), gradient(5));
+
+        // every channel differs by 5, the mean squared error is 25
+        let expected = 20.0 * (255.0f64 / 5.0).log10();
+        assert!((psnr(&a, &b) - expected).abs() < 1e-9, "{} != {}", psnr(&a, &b), expected);
+        assert!(ssim(&a, &b) < 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "same dimensions")]
+    fn mismatched_images() {
+        psnr(&RgbImage::new(4, 4), &RgbImage::new(4, 5));
+    }
+
+    #[test]
+    #[should_panic(expected = "must not be empty")]
+    fn empty_images() {
+        ssim(&RgbImage::new(0, 4), &RgbImage::new(0, 4));
+    }
+}
@@ -0,0 +1,101 @@
+use image::Rgb;
+
+use crate::camera::Camera;
+use crate::fractal::Fractal;
+use crate::pixels::pixels;
+
+/// The cumulative distribution of the iterations of a screen, used to spread
+/// the colors evenly over the pixels whatever the iterations are.
+#[derive(Debug, Copy, Clone)]
+pub struct IterationsHistogram {
+    cumulative: [u64; 256],
+    min: u64,
+    total: u64,
+}
+
+impl IterationsHistogram {
+    pub fn from_iterations<I: IntoIterator<Item = u8>>(iterations: I) -> IterationsHistogram {
+        let mut cumulative = [0u64; 256];
+        for i in iterations {
+            cumulative[i as usize] += 1;
+        }
+
+        for i in 1..cumulative.len() {
+            cumulative[i] += cumulative[i - 1];
+        }
+
+        let total = cumulative[255];
+        let min = cumulative.iter().cloned().find(|&c| c != 0).unwrap_or(0);
+
+        IterationsHistogram { cumulative, min, total }
+    }
+
+    /// Maps the iterations to the `[0, 255]` range according to their rank.
+    pub fn equalize(&self, iterations: u8) -> u8 {
+        if self.total == self.min {
+            return iterations;
+        }
+
+        let rank = self.cumulative[iterations as usize].saturating_sub(self.min);
+        (rank * 255 / (self.total - self.min)) as u8
+    }
+}
+
+/// Colors the screen using histogram equalization in two passes over the pixels:
+/// the first one fills the histogram, the second one, lazily driven by the returned
+/// iterator, paints the equalized iterations.
+///
+/// The fractal is computed twice but the memory used stays the one of the histogram,
+/// the pixels can be encoded as they are yielded.
+pub fn histogram_coloring<'a, F, C>(
+    fractal: &'a F,
+    camera: &Camera,
+    dimensions: (u32, u32),
+    painter: C,
+) -> impl Iterator<Item = (u32, u32, Rgb<u8>)> + 'a
+where
+    F: Fractal + ?Sized,
+    C: Fn(u8) -> Rgb<u8> + 'a,
+{
+    let histogram = IterationsHistogram::from_iterations(
+        pixels(fractal, camera, dimensions).map(|(_, _, i)| i),
+    );
+
+    pixels(fractal, camera, dimensions).map(move |(x, y, i)| (x, y, painter(histogram.equalize(i))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uniform_is_identity() {
+        let histogram = IterationsHistogram::from_iterations(0..=255);
+
+        for i in 0..=255 {
+            let equalized = histogram.equalize(i);
+            assert!((equalized as i32 - i as i32).abs() <= 1, "{} equalized to {}", i, equalized);
+        }
+    }
+
+    #[test]
+    fn single_value() {
+        // every pixel has the same iterations, total and min are equal
+        let histogram = IterationsHistogram::from_iterations(vec![42; 100]);
+
+        for i in 0..=255 {
+            assert_eq!(histogram.equalize(i), i);
+        }
+    }
+
+    #[test]
+    fn monotonic() {
+        let iterations = (0..1000u32).map(|i| (i * i % 251) as u8);
+        let histogram = IterationsHistogram::from_iterations(iterations);
+
+        for i in 0..255 {
+            assert!(histogram.equalize(i) <= histogram.equalize(i + 1), "not monotonic at {}", i);
+        }
+        assert_eq!(histogram.equalize(255), 255);
+    }
+}
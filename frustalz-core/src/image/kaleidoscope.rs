@@ -0,0 +1,27 @@
+use image::RgbImage;
+use std::f64::consts::PI;
+use std::num::NonZeroU32;
+
+/// Mirrors the image around its center `folds` times,
+/// each wedge being the mirror of its neighbours.
+pub fn kaleidoscope(image: &RgbImage, folds: NonZeroU32) -> RgbImage {
+    let (width, height) = image.dimensions();
+    let (cx, cy) = (width as f64 / 2.0, height as f64 / 2.0);
+    let wedge = 2.0 * PI / folds.get() as f64;
+
+    RgbImage::from_fn(width, height, |x, y| {
+        let dx = x as f64 - cx;
+        let dy = y as f64 - cy;
+
+        let radius = dx.hypot(dy);
+        let mut angle = dy.atan2(dx).rem_euclid(wedge);
+        if angle > wedge / 2.0 {
+            angle = wedge - angle;
+        }
+
+        let sx = (cx + radius * angle.cos()).max(0.0).min(width as f64 - 1.0);
+        let sy = (cy + radius * angle.sin()).max(0.0).min(height as f64 - 1.0);
+
+        *image.get_pixel(sx as u32, sy as u32)
+    })
+}
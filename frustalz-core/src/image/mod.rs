@@ -0,0 +1,250 @@
+mod antialiazing;
+mod complex_palette;
+mod diff;
+mod histogram_coloring;
+mod kaleidoscope;
+mod orbit_coloring;
+mod retro;
+mod scopes;
+mod screen_dimensions;
+mod seamless;
+mod stylize;
+mod sub_gradient;
+mod text_art;
+mod tone_mapping;
+mod zoom_strip;
+
+pub use self::antialiazing::Antialiazing;
+pub use self::complex_palette::ComplexPalette;
+pub use self::diff::{difference_heatmap, psnr, ssim};
+pub use self::histogram_coloring::{histogram_coloring, IterationsHistogram};
+pub use self::kaleidoscope::kaleidoscope;
+pub use self::orbit_coloring::{
+    period, produce_orbit_image, produce_orbit_image_with_progress, Coloring,
+};
+pub use self::retro::{retro, RetroPalette};
+pub use self::scopes::scopes;
+pub use self::screen_dimensions::ScreenDimensions;
+pub use self::seamless::{make_seamless, wrap_discontinuity};
+pub use self::stylize::{chromatic_aberration, film_grain, GrainStrength};
+pub use self::sub_gradient::SubGradient;
+pub use self::text_art::{braille_art, text_art, TextColor, DEFAULT_RAMP};
+pub use self::tone_mapping::ToneMapping;
+pub use self::zoom_strip::zoom_strip;
+
+use image::{imageops, FilterType, Rgb, RgbImage};
+use rayon::prelude::*;
+use std::sync::Mutex;
+
+use crate::camera::Camera;
+use crate::fractal::Fractal;
+
+pub fn edges(image: &RgbImage) -> RgbImage {
+    let kernel = [-1.0, -1.0, -1.0, -1.0, 8.0, -1.0, -1.0, -1.0, -1.0];
+    imageops::filter3x3(image, &kernel)
+}
+
+/// Renders the rows of the image in parallel, `progress` is called with the fraction
+/// of rendered rows each time another percent of them is done.
+pub(crate) fn render_rows<F, P>(dimensions: (u32, u32), pixel: F, progress: P) -> RgbImage
+where
+    F: Fn(u32, u32) -> Rgb<u8> + Sync,
+    P: Fn(f64) + Sync,
+{
+    let (width, height) = dimensions;
+    let mut image = RgbImage::new(width, height);
+    if width == 0 || height == 0 {
+        return image;
+    }
+
+    // the lock keeps the reported fractions increasing
+    let done = Mutex::new(0);
+    let height = height as usize;
+
+    image.par_chunks_mut(width as usize * 3).enumerate().for_each(|(y, row)| {
+        for (x, p) in row.chunks_mut(3).enumerate() {
+            p.copy_from_slice(&pixel(x as u32, y as u32).data);
+        }
+
+        let mut done = done.lock().unwrap();
+        *done += 1;
+        if *done * 100 / height != (*done - 1) * 100 / height {
+            progress(*done as f64 / height as f64);
+        }
+    });
+
+    image
+}
+
+pub fn produce_image<F, C>(
+    fractal: &F,
+    camera: &Camera,
+    dimensions: (u32, u32),
+    antialiazing: Option<u32>,
+    painter: C,
+) -> RgbImage
+where
+    F: Fractal + ?Sized + Sync,
+    C: Fn(u8) -> Rgb<u8> + Sync + Send,
+{
+    produce_image_with_progress(fractal, camera, dimensions, antialiazing, painter, |_| ())
+}
+
+/// Like `produce_image` but calling `progress` with the fraction of the image rendered,
+/// at most a hundred times.
+pub fn produce_image_with_progress<F, C, P>(
+    fractal: &F,
+    camera: &Camera,
+    dimensions: (u32, u32),
+    antialiazing: Option<u32>,
+    painter: C,
+    progress: P,
+) -> RgbImage
+where
+    F: Fractal + ?Sized + Sync,
+    C: Fn(u8) -> Rgb<u8> + Sync + Send,
+    P: Fn(f64) + Sync,
+{
+    assert!(antialiazing != Some(0), "antialiazing cannot be equal to zero, prefer 1 instead");
+
+    let (width, height) = dimensions;
+    let aa = antialiazing.unwrap_or(1) as f64;
+    let (bwidth, bheight) = (width * aa as u32, height * aa as u32);
+    let camera = Camera { screen_size: [bwidth as f64, bheight as f64], ..*camera };
+
+    let image = render_rows(
+        (bwidth, bheight),
+        |x, y| {
+            let [x, y] = camera.screen_to_world([x as f64, y as f64]);
+            painter(fractal.iterations(x, y))
+        },
+        progress,
+    );
+
+    if antialiazing.is_some() {
+        imageops::resize(&image, width, height, FilterType::Triangle)
+    } else {
+        image
+    }
+}
+
+/// Renders the `rect` area (x, y, width, height) of the camera screen in pixels,
+/// the pixels are exactly the ones `produce_image` renders for the whole screen
+/// without antialiazing, the camera is not re-centered on the area.
+pub fn render_region<F, C>(
+    fractal: &F,
+    camera: &Camera,
+    rect: (u32, u32, u32, u32),
+    painter: C,
+) -> RgbImage
+where
+    F: Fractal + ?Sized + Sync,
+    C: Fn(u8) -> Rgb<u8> + Sync + Send,
+{
+    let [sx, sy] = camera.screen_size;
+    let (ox, oy, width, height) = rect;
+
+    let right = ox.checked_add(width).expect("the region is outside screen domain coordinates");
+    let bottom = oy.checked_add(height).expect("the region is outside screen domain coordinates");
+
+    assert!(f64::from(right) <= sx, "the region is outside screen domain coordinates");
+    assert!(f64::from(bottom) <= sy, "the region is outside screen domain coordinates");
+
+    let mut image = RgbImage::new(width, height);
+    image.par_chunks_mut(3).enumerate().for_each(|(i, p)| {
+        let x = i as u32 % width;
+        let y = (i as u32 - x) / width;
+
+        let pos = [(ox + x) as f64, (oy + y) as f64];
+        let [x, y] = camera.screen_to_world(pos);
+        let i = fractal.iterations(x, y);
+
+        let data = painter(i).data;
+        p.copy_from_slice(&data);
+    });
+
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fractal::Mandelbrot;
+    use crate::pixels::pixels;
+    use rayon::ThreadPoolBuilder;
+
+    fn render_with_threads(threads: usize) -> RgbImage {
+        let pool = ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+        let camera = Camera::new([32.0, 24.0]);
+
+        pool.install(|| {
+            produce_image(&Mandelbrot::new(), &camera, (32, 24), Some(4), |i| Rgb { data: [i; 3] })
+        })
+    }
+
+    #[test]
+    fn antialiazing_independent_of_threads() {
+        let single = render_with_threads(1);
+        let multi = render_with_threads(4);
+
+        assert_eq!(single.into_raw(), multi.into_raw());
+    }
+
+    #[test]
+    fn region_matches_produce_image() {
+        let camera = Camera { zoom: 2.0, center: [-0.75, 0.1], ..Camera::new([32.0, 24.0]) };
+        let fractal = Mandelbrot::new();
+        let painter = |i: u8| Rgb { data: [i; 3] };
+
+        let image = produce_image(&fractal, &camera, (32, 24), None, painter);
+        let region = render_region(&fractal, &camera, (5, 3, 20, 14), painter);
+
+        assert_eq!(region.dimensions(), (20, 14));
+        for (x, y, p) in region.enumerate_pixels() {
+            assert_eq!(p, image.get_pixel(x + 5, y + 3), "pixel ({}, {})", x, y);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "outside screen domain")]
+    fn region_overflow() {
+        let camera = Camera::new([32.0, 24.0]);
+        let painter = |i: u8| Rgb { data: [i; 3] };
+        render_region(&Mandelbrot::new(), &camera, (u32::max_value(), 0, 1, 1), painter);
+    }
+
+    #[test]
+    fn pixels_match_produce_image() {
+        let camera = Camera { zoom: 2.0, center: [-0.75, 0.1], ..Camera::new([32.0, 24.0]) };
+        let fractal = Mandelbrot::new();
+
+        let image = produce_image(&fractal, &camera, (32, 24), None, |i| Rgb { data: [i; 3] });
+
+        let mut count = 0;
+        for (x, y, i) in pixels(&fractal, &camera, (32, 24)) {
+            assert_eq!(image.get_pixel(x, y).data, [i; 3], "pixel ({}, {})", x, y);
+            count += 1;
+        }
+        assert_eq!(count, 32 * 24);
+    }
+
+    #[test]
+    fn progress_reported() {
+        let camera = Camera::new([32.0, 240.0]);
+        let fractal = Mandelbrot::new();
+        let painter = |i: u8| Rgb { data: [i; 3] };
+
+        let fractions = Mutex::new(Vec::new());
+        let progress = |done| fractions.lock().unwrap().push(done);
+        let image =
+            produce_image_with_progress(&fractal, &camera, (32, 240), Some(1), painter, progress);
+
+        let fractions = fractions.into_inner().unwrap();
+        assert_eq!(fractions.len(), 100);
+        assert!(fractions.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", fractions);
+        assert_eq!(fractions.last(), Some(&1.0));
+
+        let expected = produce_image(&fractal, &camera, (32, 240), Some(1), painter);
+        assert_eq!(image.into_raw(), expected.into_raw());
+    }
+}
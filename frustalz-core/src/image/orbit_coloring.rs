@@ -0,0 +1,205 @@
+use image::{imageops, FilterType, Rgb, RgbImage};
+use num_complex::Complex64;
+use std::f64::consts::PI;
+use std::str::FromStr;
+
+use crate::camera::Camera;
+use crate::fractal::Orbit;
+use crate::image::render_rows;
+
+/// Whether the orbit escaped before the iterations limit was reached.
+fn escaped(orbit: &[Complex64]) -> bool {
+    orbit.len() <= u8::max_value() as usize
+}
+
+/// The iterations the fractal reports for the point of this orbit.
+fn iterations(orbit: &[Complex64]) -> u8 {
+    orbit.len().saturating_sub(1).min(u8::max_value() as usize) as u8
+}
+
+/// Spreads small integers over the gradient, consecutive ones far from each other.
+fn spread(n: usize) -> u8 {
+    const GOLDEN_RATIO_CONJUGATE: f64 = 0.618_033_988_75;
+    ((n as f64 * GOLDEN_RATIO_CONJUGATE).fract() * 255.0) as u8
+}
+
+/// Returns the period of the cycle the end of the orbit fell into,
+/// the points of the cycle being at most `tolerance` apart.
+pub fn period(orbit: &[Complex64], tolerance: f64) -> Option<usize> {
+    let last = *orbit.last()?;
+    let tolerance = tolerance * tolerance;
+
+    (1..orbit.len() / 2).find(|&p| (orbit[orbit.len() - 1 - p] - last).norm_sqr() <= tolerance)
+}
+
+/// Colorings computed from the orbit of the points instead of their iterations only.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Coloring {
+    /// Tints the interior points by the period of their attracting cycle,
+    /// revealing the hyperbolic components (bulbs).
+    Period,
+    /// Paints the escaping points in two tones according to the sign of the imaginary
+    /// part of their last `z`, drawing the binary decomposition of the exterior.
+    BinaryDecomposition,
+    /// Paints the escaping points by the argument of their last `z`,
+    /// producing the radial spokes of the external angles.
+    ExternalAngle,
+    /// Paints the escaping points by their continuous electrostatic potential,
+    /// without the bands of the iterations coloring.
+    Potential,
+    /// The potential coloring overlaid with the field lines of the potential.
+    FieldLines,
+}
+
+/// The number of field lines drawn around the fractal.
+const FIELD_LINES: f64 = 24.0;
+
+/// The level of the potential `ln|z| / 2^n` of the escaping `z` after `n` iterations,
+/// as a continuous number of iterations.
+fn potential_level(orbit: &[Complex64], z: Complex64) -> u8 {
+    let level = iterations(orbit) as f64 + 1.0 - z.norm().ln().log2();
+    level.max(0.0).min((u8::max_value() - 1) as f64) as u8
+}
+
+impl Coloring {
+    /// Returns the gradient index of the point whose orbit is given.
+    pub fn index(&self, orbit: &[Complex64]) -> u8 {
+        match self {
+            Coloring::Period => {
+                if escaped(orbit) {
+                    return iterations(orbit);
+                }
+                period(orbit, 1e-9).map_or(u8::max_value(), spread)
+            }
+            Coloring::BinaryDecomposition => match orbit.last() {
+                Some(z) if escaped(orbit) => {
+                    if z.im >= 0.0 {
+                        0x40
+                    } else {
+                        0xa0
+                    }
+                }
+                _ => u8::max_value(),
+            },
+            Coloring::ExternalAngle => match orbit.last() {
+                Some(z) if escaped(orbit) => {
+                    let turns = (z.arg() / (2.0 * PI)).rem_euclid(1.0);
+                    (turns * (u8::max_value() - 1) as f64) as u8
+                }
+                _ => u8::max_value(),
+            },
+            Coloring::Potential => match orbit.last() {
+                Some(&z) if escaped(orbit) => potential_level(orbit, z),
+                _ => u8::max_value(),
+            },
+            Coloring::FieldLines => match orbit.last() {
+                Some(&z) if escaped(orbit) => {
+                    let turns = (z.arg() / (2.0 * PI)).rem_euclid(1.0);
+                    if (turns * FIELD_LINES).fract() < 0.1 {
+                        u8::max_value()
+                    } else {
+                        potential_level(orbit, z)
+                    }
+                }
+                _ => u8::max_value(),
+            },
+        }
+    }
+}
+
+impl FromStr for Coloring {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "period" => Ok(Coloring::Period),
+            "binary-decomposition" => Ok(Coloring::BinaryDecomposition),
+            "external-angle" => Ok(Coloring::ExternalAngle),
+            "potential" => Ok(Coloring::Potential),
+            "field-lines" => Ok(Coloring::FieldLines),
+            _ => Err("invalid coloring, expected \"period\", \"binary-decomposition\", \
+                      \"external-angle\", \"potential\" or \"field-lines\""),
+        }
+    }
+}
+
+/// Like `produce_image` but painting each pixel from the orbit of its point.
+pub fn produce_orbit_image<F, C>(
+    fractal: &F,
+    camera: &Camera,
+    dimensions: (u32, u32),
+    antialiazing: Option<u32>,
+    painter: C,
+) -> RgbImage
+where
+    F: Orbit + ?Sized + Sync,
+    C: Fn(&[Complex64]) -> Rgb<u8> + Sync + Send,
+{
+    produce_orbit_image_with_progress(fractal, camera, dimensions, antialiazing, painter, |_| ())
+}
+
+/// Like `produce_orbit_image` but calling `progress` with the fraction of the image rendered,
+/// at most a hundred times.
+pub fn produce_orbit_image_with_progress<F, C, P>(
+    fractal: &F,
+    camera: &Camera,
+    dimensions: (u32, u32),
+    antialiazing: Option<u32>,
+    painter: C,
+    progress: P,
+) -> RgbImage
+where
+    F: Orbit + ?Sized + Sync,
+    C: Fn(&[Complex64]) -> Rgb<u8> + Sync + Send,
+    P: Fn(f64) + Sync,
+{
+    assert!(antialiazing != Some(0), "antialiazing cannot be equal to zero, prefer 1 instead");
+
+    let (width, height) = dimensions;
+    let aa = antialiazing.unwrap_or(1) as f64;
+    let (bwidth, bheight) = (width * aa as u32, height * aa as u32);
+    let camera = Camera { screen_size: [bwidth as f64, bheight as f64], ..*camera };
+
+    let image = render_rows(
+        (bwidth, bheight),
+        |x, y| {
+            let [x, y] = camera.screen_to_world([x as f64, y as f64]);
+            painter(&fractal.orbit(x, y))
+        },
+        progress,
+    );
+
+    if antialiazing.is_some() {
+        imageops::resize(&image, width, height, FilterType::Triangle)
+    } else {
+        image
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fractal::Mandelbrot;
+
+    fn orbit(x: f64, y: f64) -> Vec<Complex64> {
+        Mandelbrot::new().orbit(x, y)
+    }
+
+    #[test]
+    fn main_cardioid_period() {
+        assert_eq!(period(&orbit(0.0, 0.0), 1e-9), Some(1));
+        assert_eq!(period(&orbit(-0.1, 0.1), 1e-9), Some(1));
+    }
+
+    #[test]
+    fn period_two_bulb() {
+        assert_eq!(period(&orbit(-1.0, 0.0), 1e-9), Some(2));
+    }
+
+    #[test]
+    fn escaping_point_painted_by_iterations() {
+        let orbit = orbit(2.0, 0.0);
+        assert!(escaped(&orbit));
+        assert_eq!(Coloring::Period.index(&orbit), iterations(&orbit));
+    }
+}
@@ -0,0 +1,113 @@
+use image::{Rgb, RgbImage};
+use std::str::FromStr;
+
+const EGA: [[u8; 3]; 16] = [
+    [0x00, 0x00, 0x00],
+    [0x00, 0x00, 0xaa],
+    [0x00, 0xaa, 0x00],
+    [0x00, 0xaa, 0xaa],
+    [0xaa, 0x00, 0x00],
+    [0xaa, 0x00, 0xaa],
+    [0xaa, 0x55, 0x00],
+    [0xaa, 0xaa, 0xaa],
+    [0x55, 0x55, 0x55],
+    [0x55, 0x55, 0xff],
+    [0x55, 0xff, 0x55],
+    [0x55, 0xff, 0xff],
+    [0xff, 0x55, 0x55],
+    [0xff, 0x55, 0xff],
+    [0xff, 0xff, 0x55],
+    [0xff, 0xff, 0xff],
+];
+
+const PICO8: [[u8; 3]; 16] = [
+    [0x00, 0x00, 0x00],
+    [0x1d, 0x2b, 0x53],
+    [0x7e, 0x25, 0x53],
+    [0x00, 0x87, 0x51],
+    [0xab, 0x52, 0x36],
+    [0x5f, 0x57, 0x4f],
+    [0xc2, 0xc3, 0xc7],
+    [0xff, 0xf1, 0xe8],
+    [0xff, 0x00, 0x4d],
+    [0xff, 0xa3, 0x00],
+    [0xff, 0xec, 0x27],
+    [0x00, 0xe4, 0x36],
+    [0x29, 0xad, 0xff],
+    [0x83, 0x76, 0x9c],
+    [0xff, 0x77, 0xa8],
+    [0xff, 0xcc, 0xaa],
+];
+
+/// Fixed palettes of old hardware the images can be reduced to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum RetroPalette {
+    /// The 16 colors of the EGA graphics adapter.
+    Ega,
+    /// The 16 colors of the PICO-8 fantasy console.
+    Pico8,
+}
+
+impl RetroPalette {
+    pub fn colors(&self) -> &'static [[u8; 3]] {
+        match self {
+            RetroPalette::Ega => &EGA,
+            RetroPalette::Pico8 => &PICO8,
+        }
+    }
+
+    fn nearest(&self, color: [f32; 3]) -> [u8; 3] {
+        let distance = |c: &[u8; 3]| (0..3).map(|i| (color[i] - c[i] as f32).powi(2)).sum::<f32>();
+
+        let colors = self.colors().iter();
+        *colors.min_by(|a, b| distance(a).partial_cmp(&distance(b)).unwrap()).unwrap()
+    }
+}
+
+impl FromStr for RetroPalette {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "ega" => Ok(RetroPalette::Ega),
+            "pico8" => Ok(RetroPalette::Pico8),
+            _ => Err("invalid retro palette, expected \"ega\" or \"pico8\""),
+        }
+    }
+}
+
+/// Reduces the image to the colors of the palette, the quantization error
+/// is spread over the neighbouring pixels using Floyd–Steinberg dithering.
+pub fn retro(image: &mut RgbImage, palette: RetroPalette) {
+    let (width, height) = image.dimensions();
+    let width = width as usize;
+
+    // the errors diffused to the current and next rows
+    let mut current = vec![[0.0f32; 3]; width + 2];
+    let mut next = vec![[0.0f32; 3]; width + 2];
+
+    for y in 0..height {
+        for x in 0..width {
+            let p = image.get_pixel_mut(x as u32, y);
+
+            let mut color = [0.0; 3];
+            for c in 0..3 {
+                color[c] = (p.data[c] as f32 + current[x + 1][c]).max(0.0).min(255.0);
+            }
+
+            let quantized = palette.nearest(color);
+            *p = Rgb { data: quantized };
+
+            for c in 0..3 {
+                let error = color[c] - quantized[c] as f32;
+                current[x + 2][c] += error * 7.0 / 16.0;
+                next[x][c] += error * 3.0 / 16.0;
+                next[x + 1][c] += error * 5.0 / 16.0;
+                next[x + 2][c] += error / 16.0;
+            }
+        }
+
+        current = next;
+        next = vec![[0.0; 3]; width + 2];
+    }
+}
@@ -0,0 +1,57 @@
+use image::{imageops, Rgb, RgbImage};
+
+pub(crate) fn luminance(p: &Rgb<u8>) -> u8 {
+    let [r, g, b] = p.data;
+    (0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64).round() as u8
+}
+
+/// The luminance histogram of the image, bins are drawn from left (black)
+/// to right (white) and scaled to the most populated one.
+fn histogram(image: &RgbImage, height: u32) -> RgbImage {
+    let mut bins = [0u32; 256];
+    for p in image.pixels() {
+        bins[luminance(p) as usize] += 1;
+    }
+
+    let max = bins.iter().cloned().max().unwrap_or(0).max(1);
+    RgbImage::from_fn(256, height, |x, y| {
+        let bar = (bins[x as usize] as u64 * height as u64 / max as u64) as u32;
+        if height - y <= bar {
+            Rgb { data: [255; 3] }
+        } else {
+            Rgb { data: [0; 3] }
+        }
+    })
+}
+
+/// The per-channel waveform of the image: each column shows the distribution
+/// of the channel values of the same image column, 255 being at the top.
+fn waveform(image: &RgbImage) -> RgbImage {
+    let (width, height) = image.dimensions();
+    let gain = (255 * 16 / height.max(1)).max(1).min(255) as u8;
+
+    let mut scope = RgbImage::new(width, 256);
+    for (x, _, p) in image.enumerate_pixels() {
+        for channel in 0..3 {
+            let value = p.data[channel] as u32;
+            let dot = scope.get_pixel_mut(x, 255 - value);
+            dot.data[channel] = dot.data[channel].saturating_add(gain);
+        }
+    }
+
+    scope
+}
+
+/// Produces a debug panel with the image in the top-left corner,
+/// its luminance histogram on the right and its per-channel waveform below it,
+/// like a video scope, to help tuning palettes and exposure.
+pub fn scopes(image: &RgbImage) -> RgbImage {
+    let (width, height) = image.dimensions();
+
+    let mut panel = RgbImage::new(width + 256, height + 256);
+    imageops::replace(&mut panel, image, 0, 0);
+    imageops::replace(&mut panel, &histogram(image, height), width, 0);
+    imageops::replace(&mut panel, &waveform(image), 0, height);
+
+    panel
+}
@@ -0,0 +1,115 @@
+use image::{Rgb, RgbImage};
+
+fn mix(a: &Rgb<u8>, b: &Rgb<u8>, t: f64) -> Rgb<u8> {
+    let mut data = [0; 3];
+    for (out, (&a, &b)) in data.iter_mut().zip(a.data.iter().zip(&b.data)) {
+        *out = (a as f64 + t * (b as f64 - a as f64)).round() as u8;
+    }
+    Rgb { data }
+}
+
+/// Produces a tileable image by cross-fading the `blend` extra pixels
+/// on the right and bottom of the given image into its left and top edges.
+///
+/// The returned image is `blend` pixels narrower and shorter than the given one,
+/// it is its top-left part: its center is `blend / 2` pixels above and left of the
+/// given image center.
+pub fn make_seamless(image: &RgbImage, blend: u32) -> RgbImage {
+    let (width, height) = image.dimensions();
+    assert!(blend < width && blend < height, "blend width must be smaller than the image");
+
+    let (width, height) = (width - blend, height - blend);
+
+    let horizontal = RgbImage::from_fn(width, height + blend, |x, y| {
+        let p = image.get_pixel(x, y);
+        if x < blend {
+            let t = x as f64 / blend as f64;
+            mix(image.get_pixel(x + width, y), p, t)
+        } else {
+            *p
+        }
+    });
+
+    RgbImage::from_fn(width, height, |x, y| {
+        let p = horizontal.get_pixel(x, y);
+        if y < blend {
+            let t = y as f64 / blend as f64;
+            mix(horizontal.get_pixel(x, y + height), p, t)
+        } else {
+            *p
+        }
+    })
+}
+
+fn distance(a: &Rgb<u8>, b: &Rgb<u8>) -> f64 {
+    a.data.iter().zip(&b.data).map(|(&a, &b)| (a as f64 - b as f64).abs()).sum()
+}
+
+/// Measures how visible the seams are when the image is tiled: the mean difference
+/// between pixels adjacent across the wrapping edges, divided by the mean difference
+/// between adjacent pixels inside the image.
+///
+/// A value close to one means the tiles wrap as smoothly as the image itself.
+pub fn wrap_discontinuity(image: &RgbImage) -> f64 {
+    let (width, height) = image.dimensions();
+
+    let (mut inner, mut inner_count) = (0.0, 0);
+    let (mut seam, mut seam_count) = (0.0, 0);
+
+    for y in 0..height {
+        for x in 0..width {
+            let p = image.get_pixel(x, y);
+            let right = image.get_pixel((x + 1) % width, y);
+            let below = image.get_pixel(x, (y + 1) % height);
+
+            for (neighbour, wraps) in &[(right, x + 1 == width), (below, y + 1 == height)] {
+                if *wraps {
+                    seam += distance(p, neighbour);
+                    seam_count += 1;
+                } else {
+                    inner += distance(p, neighbour);
+                    inner_count += 1;
+                }
+            }
+        }
+    }
+
+    let inner = inner / inner_count.max(1) as f64;
+    let seam = seam / seam_count.max(1) as f64;
+
+    if inner == 0.0 {
+        if seam == 0.0 {
+            1.0
+        } else {
+            f64::INFINITY
+        }
+    } else {
+        seam / inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping_edges_match() {
+        let (width, height, blend) = (30, 20, 8);
+        let image = RgbImage::from_fn(width + blend, height + blend, |x, y| Rgb {
+            data: [(x * 4) as u8, (y * 4) as u8, (x + y) as u8 * 2],
+        });
+
+        let seamless = make_seamless(&image, blend);
+        assert_eq!(seamless.dimensions(), (width, height));
+
+        // across the seams, the pixels are the ones that were adjacent in the given image
+        for y in blend..height {
+            assert_eq!(seamless.get_pixel(width - 1, y), image.get_pixel(width - 1, y));
+            assert_eq!(seamless.get_pixel(0, y), image.get_pixel(width, y));
+        }
+        for x in blend..width {
+            assert_eq!(seamless.get_pixel(x, height - 1), image.get_pixel(x, height - 1));
+            assert_eq!(seamless.get_pixel(x, 0), image.get_pixel(x, height));
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use image::{Rgb, RgbImage};
+use rand::Rng;
+use std::str::FromStr;
+
+/// The strength of a film grain, in `[0, 1]`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GrainStrength(f64);
+
+impl GrainStrength {
+    pub fn new(value: f64) -> Option<Self> {
+        Some(value).filter(|v| *v >= 0.0 && *v <= 1.0).map(GrainStrength)
+    }
+}
+
+impl From<GrainStrength> for f64 {
+    fn from(strength: GrainStrength) -> Self {
+        strength.0
+    }
+}
+
+impl FromStr for GrainStrength {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim().parse().map_err(|_| "invalid number")?;
+
+        GrainStrength::new(value).ok_or("strength is not between 0 and 1")
+    }
+}
+
+/// Adds monochromatic noise of at most `strength` (in `[0, 1]`) to every pixel,
+/// the noise is drawn from the given generator to keep renders reproducible.
+pub fn film_grain<R: Rng>(image: &mut RgbImage, rng: &mut R, strength: f64) {
+    assert!(strength >= 0.0 && strength <= 1.0, "film grain strength must be between 0 and 1");
+
+    let amplitude = strength * 255.0;
+    if amplitude <= 0.0 {
+        return;
+    }
+
+    for p in image.pixels_mut() {
+        let noise = rng.gen_range(-amplitude, amplitude);
+        for c in p.data.iter_mut() {
+            *c = (*c as f64 + noise).round().max(0.0).min(255.0) as u8;
+        }
+    }
+}
+
+/// Shifts the red channel outward and the blue channel inward from the center,
+/// the shift grows with the distance to the center up to `shift` pixels in the corners.
+pub fn chromatic_aberration(image: &RgbImage, shift: f64) -> RgbImage {
+    let (width, height) = image.dimensions();
+    let (cx, cy) = (width as f64 / 2.0, height as f64 / 2.0);
+    let max_radius = cx.hypot(cy);
+
+    let sample = |x: f64, y: f64, channel: usize| {
+        let x = x.round().max(0.0).min(width as f64 - 1.0) as u32;
+        let y = y.round().max(0.0).min(height as f64 - 1.0) as u32;
+        image.get_pixel(x, y).data[channel]
+    };
+
+    RgbImage::from_fn(width, height, |x, y| {
+        let dx = x as f64 - cx;
+        let dy = y as f64 - cy;
+
+        // a displacement of `shift` pixels in the corners, pointing outward
+        let scale = shift / max_radius;
+        let (ox, oy) = (dx * scale, dy * scale);
+
+        let r = sample(x as f64 - ox, y as f64 - oy, 0);
+        let g = image.get_pixel(x, y).data[1];
+        let b = sample(x as f64 + ox, y as f64 + oy, 2);
+
+        Rgb { data: [r, g, b] }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Isaac64Rng, SeedableRng};
+
+    fn grain(seed: u64) -> RgbImage {
+        let mut image = RgbImage::from_pixel(16, 16, Rgb { data: [128; 3] });
+        film_grain(&mut image, &mut Isaac64Rng::from_seed(&[seed]), 0.1);
+        image
+    }
+
+    #[test]
+    fn reproducible_grain() {
+        assert_eq!(grain(42).into_raw(), grain(42).into_raw());
+        assert_ne!(grain(42).into_raw(), grain(43).into_raw());
+    }
+
+    #[test]
+    fn bounded_grain() {
+        for p in grain(42).pixels() {
+            let [r, g, b] = p.data;
+            assert!(r == g && g == b, "the grain is monochromatic");
+            assert!((r as i32 - 128).abs() <= 26, "{} is too far from 128", r);
+        }
+    }
+
+    #[test]
+    fn parse_strength() {
+        assert_eq!("0.2".parse(), Ok(GrainStrength(0.2)));
+        assert_eq!("1".parse(), Ok(GrainStrength(1.0)));
+        assert!("1.5".parse::<GrainStrength>().is_err());
+        assert!("-0.1".parse::<GrainStrength>().is_err());
+        assert!("NaN".parse::<GrainStrength>().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "between 0 and 1")]
+    fn nan_strength() {
+        film_grain(&mut RgbImage::new(4, 4), &mut Isaac64Rng::from_seed(&[42]), std::f64::NAN);
+    }
+}
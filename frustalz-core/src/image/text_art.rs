@@ -0,0 +1,137 @@
+use image::{imageops, FilterType, RgbImage};
+use std::fmt::Write;
+use std::str::FromStr;
+
+use super::scopes::luminance;
+
+/// The characters used from the darkest to the brightest pixels by default.
+pub const DEFAULT_RAMP: &str = " .:-=+*#%@";
+
+/// How the characters are colored.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TextColor {
+    /// Plain text, only the characters ramp tells the brightness.
+    None,
+    /// The 256 colors ANSI escape codes supported by most terminals.
+    Ansi,
+    /// The 24 bits ANSI escape codes, supported by most modern terminals.
+    Truecolor,
+}
+
+impl TextColor {
+    fn escape(&self, out: &mut String, [r, g, b]: [u8; 3]) {
+        match self {
+            TextColor::None => (),
+            TextColor::Ansi => {
+                // the nearest color of the 6x6x6 cube of the 256 colors palette
+                let cube = |c: u8| (c as u16 * 5 / 255) as u8;
+                let index = 16 + 36 * cube(r) + 6 * cube(g) + cube(b);
+                let _ = write!(out, "\x1b[38;5;{}m", index);
+            }
+            TextColor::Truecolor => {
+                let _ = write!(out, "\x1b[38;2;{};{};{}m", r, g, b);
+            }
+        }
+    }
+
+    fn reset(&self, out: &mut String) {
+        if *self != TextColor::None {
+            out.push_str("\x1b[0m");
+        }
+    }
+}
+
+impl FromStr for TextColor {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "none" => Ok(TextColor::None),
+            "ansi" => Ok(TextColor::Ansi),
+            "truecolor" => Ok(TextColor::Truecolor),
+            _ => Err("invalid text color, expected \"none\", \"ansi\" or \"truecolor\""),
+        }
+    }
+}
+
+/// Renders the image as `columns` characters wide lines of text, each character
+/// is picked in the `ramp` (from dark to bright) according to the pixel luminance.
+///
+/// Characters being about twice as high as wide, a line covers two pixels rows.
+pub fn text_art(image: &RgbImage, columns: u32, ramp: &str, color: TextColor) -> String {
+    let ramp: Vec<char> = ramp.chars().collect();
+    let (width, height) = image.dimensions();
+    if ramp.is_empty() || columns == 0 || width == 0 {
+        return String::new();
+    }
+
+    let rows = (columns as u64 * height as u64 / width as u64 / 2).max(1) as u32;
+    let image = imageops::resize(image, columns, rows, FilterType::Triangle);
+
+    let mut out = String::new();
+    for y in 0..rows {
+        for x in 0..columns {
+            let p = image.get_pixel(x, y);
+            let index = luminance(p) as usize * (ramp.len() - 1) / 255;
+            color.escape(&mut out, p.data);
+            out.push(ramp[index]);
+        }
+        color.reset(&mut out);
+        out.push('\n');
+    }
+
+    out
+}
+
+/// The bit of each dot of a braille cell, indexed by `[y][x]`.
+const BRAILLE_DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Renders the image as `columns` characters wide lines of braille cells,
+/// each cell shows 2 by 4 pixels as dots, lit when brighter than the cell mean.
+///
+/// The color of a cell is the mean color of its lit dots.
+pub fn braille_art(image: &RgbImage, columns: u32, color: TextColor) -> String {
+    let (width, height) = image.dimensions();
+    if columns == 0 || width == 0 {
+        return String::new();
+    }
+
+    // dots are about as high as wide
+    let rows = (columns as u64 * 2 * height as u64 / width as u64 / 4).max(1) as u32;
+    let image = imageops::resize(image, columns * 2, rows * 4, FilterType::Triangle);
+
+    let mut out = String::new();
+    for row in 0..rows {
+        for column in 0..columns {
+            let dots = (0..4).flat_map(|y| (0..2).map(move |x| (x, y)));
+            let pixel = |(x, y): (u32, u32)| image.get_pixel(column * 2 + x, row * 4 + y);
+
+            let mean = dots.clone().map(|d| luminance(pixel(d)) as u32).sum::<u32>() / 8;
+            let threshold = mean.max(16);
+
+            let mut bits = 0;
+            let mut sum = [0u32; 3];
+            let mut lit = 0;
+            for (x, y) in dots {
+                let p = pixel((x, y));
+                if luminance(p) as u32 >= threshold {
+                    bits |= BRAILLE_DOTS[y as usize][x as usize];
+                    for c in 0..3 {
+                        sum[c] += p.data[c] as u32;
+                    }
+                    lit += 1;
+                }
+            }
+
+            if lit != 0 {
+                let [r, g, b] = sum;
+                color.escape(&mut out, [(r / lit) as u8, (g / lit) as u8, (b / lit) as u8]);
+            }
+            out.push(std::char::from_u32(0x2800 + bits).unwrap_or(' '));
+        }
+        color.reset(&mut out);
+        out.push('\n');
+    }
+
+    out
+}
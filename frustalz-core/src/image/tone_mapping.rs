@@ -0,0 +1,88 @@
+use palette::rgb::LinSrgb;
+use std::str::FromStr;
+
+/// Curves applied to linear colors before quantization,
+/// compressing the highlights to keep bright areas from being blown out.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ToneMapping {
+    /// Extended Reinhard operator, reaching white at the white point.
+    Reinhard,
+    /// Hable's filmic curve, with a softer toe and shoulder.
+    Filmic,
+}
+
+/// The scale applied to the linear colors before the curves.
+const EXPOSURE: f32 = 1.5;
+
+/// The linear value mapped to white, the brightest one of the palettes.
+const WHITE: f32 = 1.0;
+
+fn hable(x: f32) -> f32 {
+    let (a, b, c, d, e, f) = (0.15, 0.50, 0.10, 0.20, 0.02, 0.30);
+    ((x * (a * x + c * b) + d * e) / (x * (a * x + b) + d * f)) - e / f
+}
+
+impl ToneMapping {
+    fn map_channel(&self, c: f32) -> f32 {
+        match self {
+            ToneMapping::Reinhard => {
+                let (x, white) = (c * EXPOSURE, WHITE * EXPOSURE);
+                x * (1.0 + x / (white * white)) / (1.0 + x)
+            }
+            ToneMapping::Filmic => hable(c * EXPOSURE) / hable(WHITE * EXPOSURE),
+        }
+    }
+
+    pub fn apply(&self, color: LinSrgb) -> LinSrgb {
+        LinSrgb::new(
+            self.map_channel(color.red),
+            self.map_channel(color.green),
+            self.map_channel(color.blue),
+        )
+    }
+}
+
+impl FromStr for ToneMapping {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "reinhard" => Ok(ToneMapping::Reinhard),
+            "filmic" => Ok(ToneMapping::Filmic),
+            _ => Err("invalid tone mapping, expected \"reinhard\" or \"filmic\""),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CURVES: [ToneMapping; 2] = [ToneMapping::Reinhard, ToneMapping::Filmic];
+
+    #[test]
+    fn black_and_white() {
+        for curve in &CURVES {
+            assert!(curve.map_channel(0.0).abs() < 1e-6, "{:?}", curve);
+            assert!((curve.map_channel(WHITE) - 1.0).abs() < 1e-6, "{:?}", curve);
+        }
+    }
+
+    #[test]
+    fn monotonic() {
+        for curve in &CURVES {
+            let values: Vec<_> = (0..=100).map(|i| curve.map_channel(i as f32 / 100.0)).collect();
+            for pair in values.windows(2) {
+                assert!(pair[0] < pair[1], "{:?} is not increasing: {:?}", curve, pair);
+            }
+        }
+    }
+
+    #[test]
+    fn compressed_highlights() {
+        for curve in &CURVES {
+            let slope = (curve.map_channel(WHITE) - curve.map_channel(0.9 * WHITE)) / 0.1;
+            assert!(slope < 1.0, "{:?} does not compress the highlights", curve);
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use image::{imageops, Rgb, RgbImage};
+use std::num::NonZeroU32;
+
+use crate::camera::Camera;
+use crate::fractal::Fractal;
+use crate::image::produce_image;
+
+/// Renders the camera center at `levels` zoom levels logarithmically spaced
+/// between the whole fractal (a zoom of one) and the camera zoom,
+/// the images are placed side by side from left to right.
+pub fn zoom_strip<F, C>(
+    fractal: &F,
+    camera: &Camera,
+    dimensions: (u32, u32),
+    levels: NonZeroU32,
+    antialiazing: Option<u32>,
+    painter: C,
+) -> RgbImage
+where
+    F: Fractal + ?Sized + Sync,
+    C: Fn(u8) -> Rgb<u8> + Sync + Send,
+{
+    let levels = levels.get();
+    let (width, height) = dimensions;
+    let mut strip = RgbImage::new(width * levels, height);
+
+    for level in 0..levels {
+        let t = if levels == 1 { 1.0 } else { level as f64 / (levels - 1) as f64 };
+        let camera = Camera { zoom: camera.zoom.powf(t), ..camera.clone() };
+
+        let image = produce_image(fractal, &camera, dimensions, antialiazing, &painter);
+        imageops::replace(&mut strip, &image, level * width, 0);
+    }
+
+    strip
+}
@@ -0,0 +1,25 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod camera;
+pub mod capabilities;
+pub mod fractal;
+#[cfg(feature = "std")]
+pub mod generate;
+#[cfg(feature = "std")]
+pub mod image;
+pub mod pixels;
+#[cfg(feature = "gui")]
+pub mod preview;
+#[cfg(feature = "std")]
+pub mod seed;
+#[cfg(feature = "std")]
+pub mod status;
+pub mod tile;
+
+pub use crate::camera::{Camera, ZoomTooDeep};
+pub use crate::fractal::{Fractal, Orbit};
+pub use crate::fractal::{Julia, Mandelbrot};
+pub use crate::pixels::{pixels, Pixels};
+pub use crate::tile::{render_tile, Tile};
@@ -0,0 +1,55 @@
+use crate::camera::Camera;
+use crate::fractal::Fractal;
+
+/// A lazy iterator over the pixels of a screen, in row-major order,
+/// yielding the `(x, y, iterations)` of each pixel one by one.
+#[derive(Debug, Clone)]
+pub struct Pixels<'a, F: ?Sized> {
+    fractal: &'a F,
+    camera: Camera,
+    dimensions: (u32, u32),
+    index: u64,
+}
+
+/// Returns an iterator computing the iterations of the pixels of the camera
+/// rendered in `dimensions` only when asked, so consumers can run in constant memory.
+///
+/// The iterations are exactly the ones `produce_image` paints without antialiazing.
+pub fn pixels<'a, F>(fractal: &'a F, camera: &Camera, dimensions: (u32, u32)) -> Pixels<'a, F>
+where
+    F: Fractal + ?Sized,
+{
+    let (width, height) = dimensions;
+    let camera = Camera { screen_size: [width as f64, height as f64], ..camera.clone() };
+
+    Pixels { fractal, camera, dimensions, index: 0 }
+}
+
+impl<'a, F> Iterator for Pixels<'a, F>
+where
+    F: Fractal + ?Sized,
+{
+    type Item = (u32, u32, u8);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (width, height) = self.dimensions;
+        if self.index >= u64::from(width) * u64::from(height) {
+            return None;
+        }
+
+        let x = (self.index % u64::from(width)) as u32;
+        let y = (self.index / u64::from(width)) as u32;
+        self.index += 1;
+
+        let [wx, wy] = self.camera.screen_to_world([x as f64, y as f64]);
+        Some((x, y, self.fractal.iterations(wx, wy)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (width, height) = self.dimensions;
+        let remaining = (u64::from(width) * u64::from(height) - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, F> ExactSizeIterator for Pixels<'a, F> where F: Fractal + ?Sized {}
@@ -0,0 +1,87 @@
+use image::{imageops, FilterType, RgbImage};
+use minifb::{Key, KeyRepeat, MouseButton, MouseMode, Window, WindowOptions};
+use std::thread;
+use std::time::Duration;
+
+/// A small window displaying the images produced while diving into a fractal.
+///
+/// Pressing space pauses the dive, while paused clicking on a pixel
+/// chooses the next target point, pressing space again resumes the dive.
+pub struct Preview {
+    window: Window,
+    dimensions: (u32, u32),
+    buffer: Vec<u32>,
+    paused: bool,
+    target: Option<(u32, u32)>,
+}
+
+impl Preview {
+    pub fn new(title: &str, dimensions: (u32, u32)) -> minifb::Result<Preview> {
+        let (width, height) = dimensions;
+        let window = Window::new(title, width as usize, height as usize, WindowOptions::default())?;
+        let buffer = vec![0; (width * height) as usize];
+
+        Ok(Preview { window, dimensions, buffer, paused: false, target: None })
+    }
+
+    /// Displays the given image, resizing it to the window dimensions if needed.
+    pub fn show(&mut self, image: &RgbImage) {
+        let (width, height) = self.dimensions;
+
+        let resized;
+        let image = if image.dimensions() != self.dimensions {
+            resized = imageops::resize(image, width, height, FilterType::Triangle);
+            &resized
+        } else {
+            image
+        };
+
+        for (out, p) in self.buffer.iter_mut().zip(image.pixels()) {
+            let [r, g, b] = p.data;
+            *out = (r as u32) << 16 | (g as u32) << 8 | b as u32;
+        }
+
+        let _ = self.window.update_with_buffer(&self.buffer);
+        self.handle_input();
+    }
+
+    /// Blocks until the user resumes the dive or closes the window.
+    pub fn wait_while_paused(&mut self) {
+        while self.paused && self.is_open() {
+            self.window.update();
+            self.handle_input();
+            thread::sleep(Duration::from_millis(16));
+        }
+    }
+
+    /// Whether a target point has been chosen by the user and not yet taken.
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    /// Takes the target point chosen by the user, in screen coordinates.
+    pub fn take_target(&mut self) -> Option<(u32, u32)> {
+        self.target.take()
+    }
+
+    fn handle_input(&mut self) {
+        if self.window.is_key_pressed(Key::Space, KeyRepeat::No) {
+            self.paused = !self.paused;
+        }
+
+        if self.paused && self.window.get_mouse_down(MouseButton::Left) {
+            let (width, height) = self.dimensions;
+            if let Some((x, y)) = self.window.get_mouse_pos(MouseMode::Discard) {
+                let x = (x.max(0.0) as u32).min(width - 1);
+                let y = (y.max(0.0) as u32).min(height - 1);
+                self.target = Some((x, y));
+            }
+        }
+    }
+
+    /// Returns `false` if the window has been closed or escape pressed,
+    /// meaning that the dive must stop there.
+    pub fn is_open(&self) -> bool {
+        self.window.is_open() && !self.window.is_key_down(Key::Escape)
+    }
+}
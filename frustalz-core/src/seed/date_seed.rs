@@ -0,0 +1,121 @@
+use chrono::{DateTime, Duration, Local, NaiveDateTime, TimeZone, Utc};
+use std::str::FromStr;
+
+/// The granularity at which seed dates are rounded down.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Cadence {
+    HalfHourly,
+    Hourly,
+    Daily,
+}
+
+impl Cadence {
+    pub fn period(&self) -> Duration {
+        match self {
+            Cadence::HalfHourly => Duration::minutes(30),
+            Cadence::Hourly => Duration::hours(1),
+            Cadence::Daily => Duration::days(1),
+        }
+    }
+}
+
+impl Default for Cadence {
+    fn default() -> Self {
+        Cadence::Hourly
+    }
+}
+
+impl FromStr for Cadence {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "30min" | "half-hourly" => Ok(Cadence::HalfHourly),
+            "hourly" => Ok(Cadence::Hourly),
+            "daily" => Ok(Cadence::Daily),
+            _ => Err("invalid cadence, expected \"30min\", \"hourly\" or \"daily\""),
+        }
+    }
+}
+
+/// The offset applied to the cadence periods boundaries, in minutes.
+///
+/// It is at most a day in either direction, the longest cadence period.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SeedOffset(pub Duration);
+
+impl FromStr for SeedOffset {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let minutes: i64 = s.trim().parse().map_err(|_| "invalid offset, expected minutes")?;
+        if minutes.abs() > 24 * 60 {
+            return Err("the offset must be within a day (1440 minutes)");
+        }
+        Ok(SeedOffset(Duration::minutes(minutes)))
+    }
+}
+
+/// Parses a datetime with an explicit offset (e.g. RFC3339) or,
+/// if there is none, a datetime in the local timezone, converted to UTC.
+fn parse_datetime(s: &str) -> Result<DateTime<Utc>, &'static str> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(s) {
+        return Ok(datetime.with_timezone(&Utc));
+    }
+
+    if let Ok(datetime) = DateTime::from_str(s) {
+        return Ok(datetime);
+    }
+
+    let naive = NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S"))
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M"))
+        .or_else(|_| NaiveDateTime::parse_from_str(&format!("{}:00", s), "%Y-%m-%d %H:%M"))
+        .map_err(|_| {
+            "invalid date, expected an RFC3339 date (e.g. 2018-06-21T13:00:00Z) \
+             or a local date formatted as \"YYYY-MM-DD HH\" or \"YYYY-MM-DD HH:MM:SS\""
+        })?;
+
+    Local
+        .from_local_datetime(&naive)
+        .single()
+        .map(|datetime| datetime.with_timezone(&Utc))
+        .ok_or("ambiguous or nonexistent date in the local timezone")
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct DateSeed(pub DateTime<Utc>);
+
+impl DateSeed {
+    /// Rounds the date down to the start of its cadence period,
+    /// periods boundaries are shifted by the given offset.
+    ///
+    /// Offsets that differ by a whole number of periods give the same boundaries.
+    /// Two instances whose offsets do not, e.g. 0 and 15 minutes with an hourly cadence,
+    /// never use the same seed date.
+    pub fn floor(self, cadence: Cadence, offset: Duration) -> Result<DateSeed, &'static str> {
+        let DateSeed(datetime) = self;
+
+        let period = cadence.period().num_seconds();
+        let offset = offset.num_seconds().rem_euclid(period);
+
+        let timestamp = datetime.timestamp() - offset;
+        let floored = timestamp - timestamp.rem_euclid(period) + offset;
+
+        Utc.timestamp_opt(floored, 0).single().map(DateSeed).ok_or("the seed date is out of range")
+    }
+}
+
+impl FromStr for DateSeed {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_datetime(s.trim()).map(DateSeed)
+    }
+}
+
+impl Default for DateSeed {
+    fn default() -> Self {
+        DateSeed(Utc::now())
+    }
+}
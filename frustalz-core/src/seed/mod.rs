@@ -0,0 +1,134 @@
+//! Deterministic derivation of the random number generator used to generate
+//! an image from a date, the same date always produces the same image.
+//!
+//! The algorithm is stable across platforms and versions:
+//!   - the date is rounded down to the start of its cadence period (see `DateSeed::floor`)
+//!   - the 64 bits FNV-1a hash of the big endian Unix timestamp (in seconds)
+//!     of this date is computed, followed by the bytes of the salt if any
+//!   - this hash seeds an ISAAC-64 random number generator
+
+mod date_seed;
+
+pub use self::date_seed::{Cadence, DateSeed, SeedOffset};
+
+use rand::{Isaac64Rng, SeedableRng};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME))
+}
+
+/// Derives the seed of an already rounded date, mixing in the salt if any.
+pub fn derive_seed(date: DateSeed, salt: Option<&str>) -> u64 {
+    let DateSeed(datetime) = date;
+
+    let timestamp = datetime.timestamp() as u64;
+    let mut bytes = [0; 8];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = (timestamp >> (56 - i * 8)) as u8;
+    }
+
+    let hash = fnv1a(FNV_OFFSET_BASIS, &bytes);
+    fnv1a(hash, salt.unwrap_or_default().as_bytes())
+}
+
+/// Constructs the random number generator of an already rounded date.
+pub fn rng(date: DateSeed, salt: Option<&str>) -> Isaac64Rng {
+    Isaac64Rng::from_seed(&[derive_seed(date, salt)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Local};
+    use rand::Rng;
+
+    fn date(s: &str) -> DateSeed {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_rfc3339() {
+        let DateSeed(datetime) = date("2018-06-21T15:00:00+02:00");
+        assert_eq!(datetime.to_rfc3339(), "2018-06-21T13:00:00+00:00");
+        assert!("2018-06-21T13".parse::<DateSeed>().is_err());
+    }
+
+    #[test]
+    fn parse_local() {
+        let local = |DateSeed(datetime): DateSeed| {
+            datetime.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S").to_string()
+        };
+
+        assert_eq!(local(date("2018-06-21 13")), "2018-06-21 13:00:00");
+        assert_eq!(local(date("2018-06-21 13:47")), "2018-06-21 13:47:00");
+        assert_eq!(local(date("2018-06-21 13:47:12")), "2018-06-21 13:47:12");
+        assert_eq!(local(date(" 2018-06-21T13:47:12 ")), "2018-06-21 13:47:12");
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let error = "21/06/2018".parse::<DateSeed>().unwrap_err();
+        assert!(error.starts_with("invalid date, expected an RFC3339 date"), "{}", error);
+        assert!("2018-06-21 25".parse::<DateSeed>().is_err());
+        assert!("".parse::<DateSeed>().is_err());
+    }
+
+    #[test]
+    fn floor_cadences() {
+        let seed = date("2018-06-21T13:47:12Z");
+        let none = Duration::zero();
+
+        assert_eq!(seed.floor(Cadence::HalfHourly, none).unwrap(), date("2018-06-21T13:30:00Z"));
+        assert_eq!(seed.floor(Cadence::Hourly, none).unwrap(), date("2018-06-21T13:00:00Z"));
+        assert_eq!(seed.floor(Cadence::Daily, none).unwrap(), date("2018-06-21T00:00:00Z"));
+    }
+
+    #[test]
+    fn floor_with_offset() {
+        let seed = date("2018-06-21T13:07:12Z");
+
+        let offset = Duration::minutes(15);
+        assert_eq!(seed.floor(Cadence::Hourly, offset).unwrap(), date("2018-06-21T12:15:00Z"));
+
+        let offset = Duration::minutes(-15);
+        assert_eq!(seed.floor(Cadence::Hourly, offset).unwrap(), date("2018-06-21T12:45:00Z"));
+
+        // offsets a whole number of periods apart give the same boundaries
+        let offset = Duration::minutes(2 * 60 + 15);
+        assert_eq!(seed.floor(Cadence::Hourly, offset).unwrap(), date("2018-06-21T12:15:00Z"));
+    }
+
+    #[test]
+    fn parse_offset() {
+        assert_eq!("15".parse(), Ok(SeedOffset(Duration::minutes(15))));
+        assert_eq!(" -90 ".parse(), Ok(SeedOffset(Duration::minutes(-90))));
+        assert_eq!("1440".parse(), Ok(SeedOffset(Duration::days(1))));
+        assert!("1441".parse::<SeedOffset>().is_err());
+        assert!("-1441".parse::<SeedOffset>().is_err());
+        assert!("9223372036854775807".parse::<SeedOffset>().is_err());
+        assert!("15min".parse::<SeedOffset>().is_err());
+    }
+
+    #[test]
+    fn stable_seed() {
+        let seed = date("2018-06-21T13:00:00Z");
+
+        assert_eq!(derive_seed(seed, None), 0xe860_98e1_771d_c1f2);
+        assert_eq!(derive_seed(seed, Some("mybotname")), 0x84f9_ac4b_dff7_d69a);
+    }
+
+    #[test]
+    fn deterministic_rng() {
+        let seed = date("2018-06-21T13:00:00Z");
+
+        let a: Vec<u64> = rng(seed, None).gen_iter().take(8).collect();
+        let b: Vec<u64> = rng(seed, None).gen_iter().take(8).collect();
+        let c: Vec<u64> = rng(seed, Some("salt")).gen_iter().take(8).collect();
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+}
@@ -0,0 +1,37 @@
+use chrono::Utc;
+use serde_derive::Serialize;
+use std::fs::{self, File};
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug, Serialize)]
+struct Status<'a> {
+    stage: &'a str,
+    percent: f64,
+    updated_at: String,
+}
+
+/// A file rewritten at each progress step, so that supervisors can tell
+/// a slow generation from a hung one by looking at the last update time.
+#[derive(Debug, Clone)]
+pub struct StatusFile {
+    path: PathBuf,
+}
+
+impl StatusFile {
+    pub fn new(path: PathBuf) -> StatusFile {
+        StatusFile { path }
+    }
+
+    /// Writes the current stage and its completion percentage,
+    /// the file is replaced atomically to never be read half written.
+    pub fn update(&self, stage: &str, percent: f64) -> io::Result<()> {
+        let status = Status { stage, percent, updated_at: Utc::now().to_rfc3339() };
+
+        let tmp_path = self.path.with_extension("tmp");
+        let file = File::create(&tmp_path)?;
+        serde_json::to_writer(file, &status).map_err(io::Error::from)?;
+
+        fs::rename(tmp_path, &self.path)
+    }
+}
@@ -0,0 +1,95 @@
+use crate::camera::Camera;
+use crate::fractal::Fractal;
+
+/// The iterations of a `W` by `H` pixels area of the screen, stored inline
+/// to avoid any heap allocation when rendering many tiles.
+#[derive(Debug, Copy, Clone)]
+pub struct Tile<const W: usize, const H: usize> {
+    /// The top-left corner of the tile in screen coordinates.
+    pub origin: (u32, u32),
+    pub iterations: [[u8; W]; H],
+}
+
+impl<const W: usize, const H: usize> Tile<W, H> {
+    /// Returns the iterations of the pixel at `(x, y)` relative to the tile origin.
+    pub fn get(&self, x: usize, y: usize) -> u8 {
+        self.iterations[y][x]
+    }
+}
+
+/// Renders the tile whose top-left corner is at `origin` in screen coordinates,
+/// pixels falling outside of the camera screen are left to zero iterations.
+///
+/// ```
+/// use frustalz_core::{fractal::Mandelbrot, tile::render_tile, Camera};
+///
+/// let camera = Camera::new([64.0, 64.0]);
+/// let tile = render_tile::<16, 16, _>(&Mandelbrot::new(), &camera, (32, 48));
+/// assert_eq!(tile.origin, (32, 48));
+/// ```
+pub fn render_tile<const W: usize, const H: usize, F>(
+    fractal: &F,
+    camera: &Camera,
+    origin: (u32, u32),
+) -> Tile<W, H>
+where
+    F: Fractal + ?Sized,
+{
+    let [sx, sy] = camera.screen_size;
+    let (ox, oy) = origin;
+
+    let mut iterations = [[0; W]; H];
+    for (y, row) in iterations.iter_mut().enumerate() {
+        let y = oy as f64 + y as f64;
+        if y >= sy {
+            break;
+        }
+
+        for (x, i) in row.iter_mut().enumerate() {
+            let x = ox as f64 + x as f64;
+            if x >= sx {
+                break;
+            }
+
+            let [x, y] = camera.screen_to_world([x, y]);
+            *i = fractal.iterations(x, y);
+        }
+    }
+
+    Tile { origin, iterations }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fractal::Mandelbrot;
+    use crate::pixels::pixels;
+
+    #[test]
+    fn tiles_match_full_frame() {
+        // the screen is not a multiple of the tile size, the last tiles are clipped
+        let (width, height) = (37, 29);
+        let camera = Camera { zoom: 2.0, center: [-0.75, 0.1], ..Camera::new([37.0, 29.0]) };
+        let fractal = Mandelbrot::new();
+
+        for (x, y, i) in pixels(&fractal, &camera, (width, height)) {
+            let origin = (x - x % 16, y - y % 16);
+            let tile = render_tile::<16, 16, _>(&fractal, &camera, origin);
+            assert_eq!(tile.get((x % 16) as usize, (y % 16) as usize), i, "pixel ({}, {})", x, y);
+        }
+    }
+
+    #[test]
+    fn clipped_tile() {
+        let camera = Camera::new([37.0, 29.0]);
+        let tile = render_tile::<16, 16, _>(&Mandelbrot::new(), &camera, (32, 16));
+
+        for y in 0..16 {
+            for x in 0..16 {
+                if x >= 5 || y >= 13 {
+                    assert_eq!(tile.get(x, y), 0, "pixel ({}, {}) is outside the screen", x, y);
+                }
+            }
+        }
+    }
+}
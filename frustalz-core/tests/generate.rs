@@ -0,0 +1,126 @@
+use chrono::{TimeZone, Utc};
+use frustalz_core::generate::{FractalInfo, FractalType, Generator, MemorySink};
+use frustalz_core::image::{Antialiazing, ScreenDimensions};
+use frustalz_core::seed::{self, DateSeed};
+use image::RgbImage;
+use num_complex::Complex64;
+use rand::Isaac64Rng;
+
+const DIVE_DIMENSIONS: (u32, u32) = (24, 16);
+const SHOT_DIMENSIONS: (u32, u32) = (32, 24);
+
+/// The 64 bits FNV-1a hash of the pixels of the image.
+fn image_hash(image: &RgbImage) -> u64 {
+    image.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn generator(sink: &MemorySink) -> Generator<Isaac64Rng> {
+    // 2019-01-01T00:00:00Z
+    let date = DateSeed(Utc.timestamp_opt(1_546_300_800, 0).unwrap());
+
+    let (dive_width, dive_height) = DIVE_DIMENSIONS;
+    let (shot_width, shot_height) = SHOT_DIMENSIONS;
+
+    let mut generator = Generator::new(seed::rng(date, None));
+    generator
+        .dive_dimensions(ScreenDimensions(dive_width, dive_height))
+        .shot_dimensions(ScreenDimensions(shot_width, shot_height))
+        .antialiazing(Antialiazing::new(1).unwrap())
+        .debug_sink(sink.clone());
+
+    generator
+}
+
+fn generate(sink: &MemorySink) -> (FractalInfo, RgbImage) {
+    generator(sink).generate()
+}
+
+#[test]
+fn whole_pipeline() {
+    let sink = MemorySink::new();
+    let (info, image) = generate(&sink);
+
+    assert!(info.zoom > 0.0 && info.zoom <= 1.0, "unexpected zoom {}", info.zoom);
+    assert!(info.position.iter().all(|c| c.is_finite()));
+    assert!(info.domain.re.is_finite() && info.domain.im.is_finite());
+    assert!(!info.to_string().is_empty());
+
+    assert_eq!(image.dimensions(), SHOT_DIMENSIONS);
+
+    let images = sink.images();
+    assert!(!images.is_empty(), "no debug image was produced while diving");
+    for (name, debug_image) in images {
+        assert!(name.starts_with("spotted-area-"), "unexpected debug image {}", name);
+        assert_eq!(debug_image.dimensions(), DIVE_DIMENSIONS);
+    }
+}
+
+#[test]
+fn deterministic_pipeline() {
+    let first_sink = MemorySink::new();
+    let (first_info, first_image) = generate(&first_sink);
+
+    let second_sink = MemorySink::new();
+    let (second_info, second_image) = generate(&second_sink);
+
+    assert_eq!(first_info.to_string(), second_info.to_string());
+    assert_eq!(first_info.zoom, second_info.zoom);
+    assert_eq!(first_info.position, second_info.position);
+    assert_eq!(image_hash(&first_image), image_hash(&second_image));
+
+    let first_debug: Vec<_> =
+        first_sink.images().iter().map(|(n, i)| (n.clone(), image_hash(i))).collect();
+    let second_debug: Vec<_> =
+        second_sink.images().iter().map(|(n, i)| (n.clone(), image_hash(i))).collect();
+    assert_eq!(first_debug, second_debug);
+}
+
+#[test]
+fn julia_companion() {
+    let sink = MemorySink::new();
+    let mut generator = generator(&sink);
+    generator.julia_companion(true);
+    generator.generate();
+
+    let (width, height) = DIVE_DIMENSIONS;
+    let companions: Vec<_> = sink
+        .images()
+        .into_iter()
+        .filter(|(name, _)| name.starts_with("julia-companion-"))
+        .collect();
+
+    assert!(!companions.is_empty(), "no julia companion was produced while diving");
+    for (_, frame) in companions {
+        assert_eq!(frame.dimensions(), (width * 2, height));
+    }
+}
+
+#[test]
+fn golden_pipeline() {
+    let (info, image) = generate(&MemorySink::new());
+
+    // the seed of this date draws a mandelbrot fractal
+    match info.fractal_type {
+        FractalType::Mandelbrot => (),
+        other => panic!("unexpected fractal type {:?}", other),
+    }
+    assert_eq!(info.domain, Complex64::new(0.0, 0.0));
+    assert_eq!(info.stop_reason, None);
+
+    // any change to the random draws, the dive or the rendering shows up here
+    insta::assert_snapshot!(
+        format!(
+            "zoom: {:?}\nposition: {:?}\nimage hash: {:#018x}",
+            info.zoom,
+            info.position,
+            image_hash(&image)
+        ),
+        @r###"
+    zoom: 0.08552848527385294
+    position: [-0.8144674193161218, -0.15132354566581538]
+    image hash: 0x63bed9c3ace5d24a
+    "###
+    );
+}
@@ -0,0 +1,12 @@
+#![no_main]
+
+use std::str::{self, FromStr};
+
+use frustalz_core::image::Antialiazing;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = str::from_utf8(data) {
+        let _ = Antialiazing::from_str(s);
+    }
+});
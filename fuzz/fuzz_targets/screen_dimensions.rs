@@ -0,0 +1,12 @@
+#![no_main]
+
+use std::str::{self, FromStr};
+
+use frustalz_core::image::ScreenDimensions;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = str::from_utf8(data) {
+        let _ = ScreenDimensions::from_str(s);
+    }
+});
@@ -1,8 +0,0 @@
-pub mod camera;
-pub mod fractal;
-pub mod generate;
-pub mod image;
-
-pub use crate::camera::Camera;
-pub use crate::fractal::Fractal;
-pub use crate::fractal::{Julia, Mandelbrot};